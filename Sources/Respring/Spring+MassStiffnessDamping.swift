@@ -50,6 +50,10 @@ extension Spring {
     /// Increasing the stiffness reduces the number of oscillations and will
     /// reduce the settling duration. Decreasing the stiffness increases the the
     /// number of oscillations and will increase the settling duration.
+    ///
+    /// This matches SwiftUI, including for overdamped springs, whose
+    /// stiffness it overstates. The Rust implementation returns the
+    /// stiffness the spring was created with instead.
     public var stiffness: Double {
         mass * (angularFrequency * angularFrequency + decayConstant * decayConstant)
     }
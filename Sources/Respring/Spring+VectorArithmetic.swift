@@ -93,6 +93,10 @@ extension Spring {
     }
 
     /// Calculates the velocity of the spring at a given time given a target amount of change.
+    ///
+    /// This matches SwiftUI, including for overdamped springs, whose velocity
+    /// it offsets by `target`. The Rust implementation returns the derivative
+    /// of the value instead.
     public func velocity<V>(target: V, initialVelocity: V = .zero, time: TimeInterval) -> V where V: VectorArithmetic {
         if angularFrequency > 0 {
             let dampingTerm = exp(-decayConstant * time)
@@ -146,6 +150,10 @@ extension Spring {
     /// Calculates the force upon the spring given a current position, target, and velocity amount of change.
     ///
     /// This value is in units of the vector type per second squared.
+    ///
+    /// This matches SwiftUI, whose spring force uses the overstated stiffness
+    /// of overdamped springs. The Rust implementation uses their actual
+    /// stiffness instead.
     public func force<V>(target: V, position: V, velocity: V) -> V where V: VectorArithmetic {
         let dampingForce = velocity.scaled(by: (-decayConstant * 2) * _mass)
         let delta = target - position
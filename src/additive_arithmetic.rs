@@ -1,3 +1,4 @@
+use std::num::Wrapping;
 use std::ops::{Add, AddAssign, Sub};
 
 pub trait AdditiveArithmetic: Add<Output = Self> + AddAssign + Sub<Output = Self> + Sized {
@@ -20,5 +21,14 @@ macro_rules! additive_arithmetic_float_impl {
     )*)
 }
 
+macro_rules! additive_arithmetic_wrapping_impl {
+    ($($t:ty)*) => ($(
+        impl AdditiveArithmetic for Wrapping<$t> {
+            const ZERO: Self = Wrapping(0);
+        }
+    )*)
+}
+
 additive_arithmetic_int_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
 additive_arithmetic_float_impl! { f32 f64 }
+additive_arithmetic_wrapping_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
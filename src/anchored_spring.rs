@@ -0,0 +1,124 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A spring driven frame by frame that does not drift from its closed-form
+/// motion, however long it runs.
+///
+/// [`Spring::update`] advances the value from the previous frame, so rounding
+/// errors accumulate with every call, which becomes visible after minutes of
+/// ambient animation, especially with `f32` values. This type instead keeps
+/// the state at which the motion started, and the time elapsed since then in
+/// `f64` with compensated summation, and evaluates the closed-form solution
+/// from that anchor on every frame. The anchor only moves when the target or
+/// the state is changed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnchoredSpring<V> {
+    pub spring: Spring,
+    origin: V,
+    target: V,
+    initial_velocity: V,
+    elapsed: f64,
+    /// The low-order part of `elapsed` lost to rounding, as in Kahan
+    /// summation.
+    compensation: f64,
+}
+
+impl<V> AnchoredSpring<V>
+where
+    V: VectorArithmetic,
+{
+    /// Creates a spring at rest at `value`, moving towards `target`.
+    pub fn new(spring: Spring, value: V, target: V) -> Self {
+        Self {
+            spring,
+            origin: value,
+            target,
+            initial_velocity: V::ZERO,
+            elapsed: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    /// Sets the initial velocity of the spring.
+    #[inline]
+    pub fn with_velocity(mut self, velocity: V) -> Self {
+        self.initial_velocity = velocity;
+        self
+    }
+
+    /// The value the spring is moving towards.
+    #[inline]
+    pub fn target(&self) -> &V {
+        &self.target
+    }
+
+    /// The time elapsed since the motion was last anchored.
+    #[inline]
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    /// The current value of the spring.
+    pub fn value(&self) -> V {
+        self.origin.clone()
+            + self.spring.value(
+                self.target.clone() - self.origin.clone(),
+                self.initial_velocity.clone(),
+                self.elapsed,
+            )
+    }
+
+    /// The current velocity of the spring.
+    pub fn velocity(&self) -> V {
+        self.spring.velocity(
+            self.target.clone() - self.origin.clone(),
+            self.initial_velocity.clone(),
+            self.elapsed,
+        )
+    }
+
+    /// Advances the spring by `delta_time` and returns the new value.
+    pub fn update(&mut self, delta_time: f64) -> V {
+        let compensated = delta_time - self.compensation;
+        let elapsed = self.elapsed + compensated;
+        self.compensation = (elapsed - self.elapsed) - compensated;
+        self.elapsed = elapsed;
+        self.value()
+    }
+
+    /// Changes the target, continuing the motion from the current value and
+    /// velocity.
+    pub fn set_target(&mut self, target: V) {
+        let (value, velocity) = (self.value(), self.velocity());
+        self.set_state(value, velocity);
+        self.target = target;
+    }
+
+    /// Moves the spring to `value` with `velocity`, keeping its target.
+    pub fn set_state(&mut self, value: V, velocity: V) {
+        self.origin = value;
+        self.initial_velocity = velocity;
+        self.elapsed = 0.0;
+        self.compensation = 0.0;
+    }
+
+    /// Returns whether the spring has come to rest at its target, as given by
+    /// [`Spring::is_settled`].
+    pub fn is_settled(&self, epsilon: f64) -> bool {
+        self.spring
+            .is_settled(&self.value(), &self.velocity(), &self.target, epsilon)
+    }
+}
+
+impl Spring {
+    /// Returns an [`AnchoredSpring`] at rest at `value` and moving towards
+    /// `target`, which drives this spring frame by frame without drift.
+    #[inline]
+    pub fn anchored<V>(&self, value: V, target: V) -> AnchoredSpring<V>
+    where
+        V: VectorArithmetic,
+    {
+        AnchoredSpring::new(*self, value, target)
+    }
+}
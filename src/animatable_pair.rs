@@ -0,0 +1,115 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A pair of animatable values, which is itself animatable.
+///
+/// Pairs can be nested to animate more than two values with a single spring.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimatablePair<First, Second> {
+    /// The first value.
+    pub first: First,
+    /// The second value.
+    pub second: Second,
+}
+
+impl<First, Second> AnimatablePair<First, Second> {
+    /// Creates an animated pair with the provided values.
+    #[inline]
+    pub const fn new(first: First, second: Second) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<First, Second> From<(First, Second)> for AnimatablePair<First, Second> {
+    #[inline]
+    fn from((first, second): (First, Second)) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<First, Second> From<AnimatablePair<First, Second>> for (First, Second) {
+    #[inline]
+    fn from(pair: AnimatablePair<First, Second>) -> Self {
+        (pair.first, pair.second)
+    }
+}
+
+impl<First, Second> Add for AnimatablePair<First, Second>
+where
+    First: AdditiveArithmetic,
+    Second: AdditiveArithmetic,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            first: self.first + rhs.first,
+            second: self.second + rhs.second,
+        }
+    }
+}
+
+impl<First, Second> AddAssign for AnimatablePair<First, Second>
+where
+    First: AdditiveArithmetic,
+    Second: AdditiveArithmetic,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.first += rhs.first;
+        self.second += rhs.second;
+    }
+}
+
+impl<First, Second> Sub for AnimatablePair<First, Second>
+where
+    First: AdditiveArithmetic,
+    Second: AdditiveArithmetic,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            first: self.first - rhs.first,
+            second: self.second - rhs.second,
+        }
+    }
+}
+
+impl<First, Second> AdditiveArithmetic for AnimatablePair<First, Second>
+where
+    First: AdditiveArithmetic,
+    Second: AdditiveArithmetic,
+{
+    const ZERO: Self = Self {
+        first: First::ZERO,
+        second: Second::ZERO,
+    };
+}
+
+impl<First, Second> VectorArithmetic for AnimatablePair<First, Second>
+where
+    First: VectorArithmetic,
+    Second: VectorArithmetic,
+{
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.first.magnitude_squared() + self.second.magnitude_squared()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.first.scale_by(scalar);
+        self.second.scale_by(scalar);
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.first.dot(&other.first) + self.second.dot(&other.second)
+    }
+}
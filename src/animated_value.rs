@@ -0,0 +1,207 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::animation::{Animation, AnimationEvent, SpringAnimation};
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A callback called with the final value of a completed transition.
+type CompletionCallback<T> = Box<dyn FnMut(&T)>;
+/// A callback called with each lifecycle event and the value at that moment.
+type EventCallback<T> = Box<dyn FnMut(AnimationEvent, &T)>;
+
+/// A value that springs to new targets, for binding to the properties of
+/// widgets.
+///
+/// [`set`](Self::set) changes the value immediately and
+/// [`animate_to`](Self::animate_to) springs to it, continuing from the current
+/// motion. The completion callback registered with
+/// [`on_complete`](Self::on_complete) fires exactly once when a transition
+/// comes to rest, however many times its target changed along the way. The
+/// callback registered with [`on_event`](Self::on_event) observes every
+/// [`AnimationEvent`] of the transitions.
+pub struct AnimatedValue<T> {
+    animation: SpringAnimation<T>,
+    on_complete: Option<CompletionCallback<T>>,
+    on_event: Option<EventCallback<T>>,
+}
+
+impl<T> AnimatedValue<T>
+where
+    T: VectorArithmetic,
+{
+    /// Creates a value at rest at `value`, which animates with `spring`.
+    pub fn new(spring: Spring, value: T) -> Self {
+        Self {
+            animation: SpringAnimation::new(spring, value),
+            on_complete: None,
+            on_event: None,
+        }
+    }
+
+    /// Creates a value at rest at `value`, which animates with the
+    /// [default spring](crate::set_default_spring) at the time it is created.
+    #[inline]
+    pub fn with_default_spring(value: T) -> Self {
+        Self::new(Spring::default(), value)
+    }
+
+    /// Sets the callback called with the final value each time a transition
+    /// completes.
+    pub fn on_complete(&mut self, callback: impl FnMut(&T) + 'static) {
+        self.on_complete = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called with each lifecycle event of the transitions
+    /// and the value at the moment it happens.
+    pub fn on_event(&mut self, callback: impl FnMut(AnimationEvent, &T) + 'static) {
+        self.animation.record_events();
+        self.on_event = Some(Box::new(callback));
+    }
+
+    /// Passes the events recorded by the animation to the event callback.
+    fn dispatch_events(&mut self) {
+        if let Some(callback) = &mut self.on_event {
+            let value = self.animation.value().clone();
+            for event in self.animation.events() {
+                callback(event, &value);
+            }
+        }
+    }
+
+    /// The current value.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.animation.value().clone()
+    }
+
+    /// The value being animated towards, or the current value at rest.
+    #[inline]
+    pub fn target(&self) -> &T {
+        self.animation.target()
+    }
+
+    /// Returns whether a transition is in progress.
+    #[inline]
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_animating()
+    }
+
+    /// The underlying animation.
+    #[inline]
+    pub fn animation(&self) -> &SpringAnimation<T> {
+        &self.animation
+    }
+
+    /// The spring used for transitions.
+    #[inline]
+    pub fn spring(&self) -> Spring {
+        self.animation.spring
+    }
+
+    /// Changes the spring used for transitions, including the one in
+    /// progress.
+    #[inline]
+    pub fn set_spring(&mut self, spring: Spring) {
+        self.animation.spring = spring;
+    }
+
+    /// Sets the time each transition waits before it starts moving, as in
+    /// [`SpringAnimation::set_delay`].
+    #[inline]
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.animation.set_delay(delay);
+    }
+
+    /// Changes the value immediately, abandoning any transition in progress
+    /// without calling the completion callback.
+    pub fn set(&mut self, value: T) {
+        self.animation.jump_to(value);
+        self.dispatch_events();
+    }
+
+    /// Springs to `target` from the current value and velocity.
+    ///
+    /// A transition in progress is continued towards the new target, and
+    /// completes only once.
+    pub fn animate_to(&mut self, target: T) {
+        self.animation.set_target(target);
+        self.dispatch_events();
+    }
+
+    /// Advances the transition in progress by `delta_time`, calling the
+    /// callbacks if it completes, and returns the current value.
+    pub fn tick(&mut self, delta_time: f64) -> T {
+        if self.animation.is_animating() && self.animation.tick(delta_time) {
+            self.dispatch_events();
+            if let Some(callback) = &mut self.on_complete {
+                callback(self.animation.value());
+            }
+        }
+        self.get()
+    }
+}
+
+impl<T> fmt::Debug for AnimatedValue<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimatedValue")
+            .field("animation", &self.animation)
+            .field("has_on_complete", &self.on_complete.is_some())
+            .field("has_on_event", &self.on_event.is_some())
+            .finish()
+    }
+}
+
+impl<T> Animation for AnimatedValue<T>
+where
+    T: VectorArithmetic,
+{
+    #[inline]
+    fn tick(&mut self, delta_time: f64) -> bool {
+        AnimatedValue::tick(self, delta_time);
+        !self.is_animating()
+    }
+
+    #[inline]
+    fn is_animating(&self) -> bool {
+        AnimatedValue::is_animating(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn default_spring_is_used() {
+        let _lock = crate::default_spring::TEST_LOCK.lock().unwrap();
+        let spring = Spring::with_duration_bounce(1.5, 0.0);
+        crate::set_default_spring(spring);
+        let value = AnimatedValue::with_default_spring(0.0);
+        crate::reset_default_spring();
+        assert_eq!(value.spring(), spring);
+    }
+
+    #[test]
+    fn completion_fires_once_across_retargets() {
+        let completions = Rc::new(Cell::new(0));
+        let mut value = AnimatedValue::new(Spring::snappy(), 0.0);
+        let counter = Rc::clone(&completions);
+        value.on_complete(move |_| counter.set(counter.get() + 1));
+        value.animate_to(1.0);
+        for frame in 0..600 {
+            if frame == 10 {
+                value.animate_to(2.0);
+            }
+            value.tick(1.0 / 60.0);
+        }
+        assert_eq!(value.get(), 2.0);
+        assert_eq!(completions.get(), 1);
+    }
+}
@@ -0,0 +1,383 @@
+use std::time::Duration;
+
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The epsilon used by [`SpringAnimation::new`].
+const DEFAULT_EPSILON: f64 = 0.001;
+
+/// A change in the lifecycle of a [`SpringAnimation`], as drained with
+/// [`SpringAnimation::events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnimationEvent {
+    /// The animation started moving from rest.
+    Started,
+    /// The target or velocity changed while the animation was moving.
+    Retargeted,
+    /// The animation came to rest at its target.
+    Finished,
+    /// The animation was stopped by a jump before reaching its target.
+    Interrupted,
+    /// A repeating animation reached its target and started its next
+    /// repetition.
+    Repeated,
+}
+
+/// How many times a [`SpringAnimation`] plays each transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RepeatCount {
+    /// The transition is played this many times in total. When the animation
+    /// autoreverses, each pass in either direction counts once. A count of 0
+    /// plays the transition once, like a count of 1.
+    Count(u32),
+    /// The transition repeats until the animation jumps to a value.
+    Forever,
+}
+
+impl Default for RepeatCount {
+    /// Returns a count of 1, which plays each transition once.
+    #[inline]
+    fn default() -> Self {
+        Self::Count(1)
+    }
+}
+
+/// A value animated by a spring towards a target that can change at any time.
+///
+/// The animation keeps the current value and velocity, so changing the
+/// target mid-flight continues the motion smoothly from the current state.
+/// Once the spring [is settled](Spring::is_settled), the value snaps to the
+/// target and the animation stops, so it always finishes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringAnimation<V> {
+    pub spring: Spring,
+    value: V,
+    velocity: V,
+    target: V,
+    epsilon: f64,
+    /// The time each transition waits before it starts moving, in seconds.
+    delay: f64,
+    /// The time left before the current transition starts moving.
+    remaining_delay: f64,
+    repeat_count: RepeatCount,
+    autoreverses: bool,
+    /// The value at which the current transition started.
+    origin: V,
+    /// The number of passes of the current transition that have finished.
+    completed_passes: u32,
+    animating: bool,
+    /// The events not yet drained, or `None` if events are not recorded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    events: Option<Vec<AnimationEvent>>,
+}
+
+impl<V> SpringAnimation<V>
+where
+    V: VectorArithmetic,
+{
+    /// Creates an animation at rest at `value`.
+    ///
+    /// The animation finishes within an epsilon of 0.001, which can be
+    /// changed with [`with_epsilon`](Self::with_epsilon).
+    pub fn new(spring: Spring, value: V) -> Self {
+        Self {
+            spring,
+            value: value.clone(),
+            velocity: V::ZERO,
+            target: value.clone(),
+            epsilon: DEFAULT_EPSILON,
+            delay: 0.0,
+            remaining_delay: 0.0,
+            repeat_count: RepeatCount::Count(1),
+            autoreverses: false,
+            origin: value,
+            completed_passes: 0,
+            animating: false,
+            events: None,
+        }
+    }
+
+    /// Creates an animation at rest at `value`, which animates with the
+    /// [default spring](crate::set_default_spring) at the time it is created.
+    #[inline]
+    pub fn with_default_spring(value: V) -> Self {
+        Self::new(Spring::default(), value)
+    }
+
+    /// Sets the threshold passed to [`Spring::is_settled`] to decide when the
+    /// animation finishes.
+    #[inline]
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Sets the time each transition waits before it starts moving, as in
+    /// [`set_delay`](Self::set_delay).
+    #[inline]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.set_delay(delay);
+        self
+    }
+
+    /// Sets the time each transition waits before it starts moving.
+    ///
+    /// The delay applies to transitions that start from rest. Changing the
+    /// target of a transition that is already delayed or moving does not
+    /// restart it. Ticks during the delay keep the value at rest, and the
+    /// part of a tick past the end of the delay moves the value.
+    #[inline]
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay.as_secs_f64();
+    }
+
+    /// The time each transition waits before it starts moving.
+    #[inline]
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs_f64(self.delay)
+    }
+
+    /// Sets how many times each transition is played, as in
+    /// [`set_repeat`](Self::set_repeat).
+    #[inline]
+    pub fn with_repeat(mut self, repeat_count: RepeatCount, autoreverses: bool) -> Self {
+        self.set_repeat(repeat_count, autoreverses);
+        self
+    }
+
+    /// Sets how many times each transition is played, and whether
+    /// alternate passes play it backwards.
+    ///
+    /// Each pass ends once the spring settles at its target, at rest, so the
+    /// next pass starts from rest as well. Without autoreversing, every pass
+    /// starts again from the value at which the transition started; with it,
+    /// passes alternate between moving to the target and back to that value.
+    #[inline]
+    pub fn set_repeat(&mut self, repeat_count: RepeatCount, autoreverses: bool) {
+        self.repeat_count = repeat_count;
+        self.autoreverses = autoreverses;
+    }
+
+    /// How many times each transition is played.
+    #[inline]
+    pub fn repeat_count(&self) -> RepeatCount {
+        self.repeat_count
+    }
+
+    /// Whether alternate passes of each transition play it backwards.
+    #[inline]
+    pub fn autoreverses(&self) -> bool {
+        self.autoreverses
+    }
+
+    /// Records the lifecycle events of the animation, to be drained with
+    /// [`events`](Self::events).
+    ///
+    /// Events are not recorded by default, so that an animation whose events
+    /// are never drained does not accumulate them.
+    #[inline]
+    pub fn with_events(mut self) -> Self {
+        self.record_events();
+        self
+    }
+
+    /// Starts recording lifecycle events, if they are not recorded already.
+    pub(crate) fn record_events(&mut self) {
+        self.events.get_or_insert_with(Vec::new);
+    }
+
+    /// Removes and returns the events recorded since the last call, oldest
+    /// first.
+    ///
+    /// This is always empty unless events were enabled with
+    /// [`with_events`](Self::with_events).
+    pub fn events(&mut self) -> impl Iterator<Item = AnimationEvent> + '_ {
+        self.events.iter_mut().flat_map(|events| events.drain(..))
+    }
+
+    fn record(&mut self, event: AnimationEvent) {
+        if let Some(events) = &mut self.events {
+            events.push(event);
+        }
+    }
+
+    /// Records that the motion changed, which starts the animation if it
+    /// was at rest.
+    fn start(&mut self) {
+        if self.animating {
+            self.record(AnimationEvent::Retargeted);
+        } else {
+            self.record(AnimationEvent::Started);
+            self.remaining_delay = self.delay;
+            self.origin = self.value.clone();
+            self.completed_passes = 0;
+        }
+        self.animating = true;
+    }
+
+    /// The current value.
+    #[inline]
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// The current velocity.
+    #[inline]
+    pub fn velocity(&self) -> &V {
+        &self.velocity
+    }
+
+    /// The value the animation is moving towards, or resting at.
+    #[inline]
+    pub fn target(&self) -> &V {
+        &self.target
+    }
+
+    /// The threshold used to decide when the animation finishes.
+    #[inline]
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Returns whether the animation is still moving towards its target.
+    #[inline]
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+
+    /// Starts moving towards `target` from the current value and velocity.
+    pub fn set_target(&mut self, target: V) {
+        self.target = target;
+        self.start();
+    }
+
+    /// Sets the current velocity, such as the velocity of a gesture that has
+    /// just ended, and resumes animating towards the target.
+    pub fn set_velocity(&mut self, velocity: V) {
+        self.velocity = velocity;
+        self.start();
+    }
+
+    /// Moves to `value` immediately and stops there at rest.
+    pub fn jump_to(&mut self, value: V) {
+        self.value = value.clone();
+        self.target = value;
+        self.velocity = V::ZERO;
+        if self.animating {
+            self.record(AnimationEvent::Interrupted);
+        }
+        self.animating = false;
+        self.remaining_delay = 0.0;
+    }
+
+    /// Starts the next pass of a repeating transition from its target, where
+    /// the previous pass came to rest.
+    fn start_next_pass(&mut self) {
+        if self.autoreverses {
+            std::mem::swap(&mut self.origin, &mut self.target);
+        } else {
+            self.value = self.origin.clone();
+        }
+        self.record(AnimationEvent::Repeated);
+    }
+
+    /// Advances the animation by `delta_time`, and returns whether it has
+    /// finished.
+    ///
+    /// Once finished, the value is exactly the target and the velocity is
+    /// zero, and further ticks do nothing until the target or velocity
+    /// changes.
+    pub fn tick(&mut self, mut delta_time: f64) -> bool {
+        if self.animating && self.remaining_delay > 0.0 {
+            let waited = delta_time.min(self.remaining_delay);
+            self.remaining_delay -= waited;
+            delta_time -= waited;
+            if self.remaining_delay > 0.0 {
+                return false;
+            }
+        }
+        if self.animating {
+            let settled = self.spring.update_with_epsilon(
+                &mut self.value,
+                &mut self.velocity,
+                self.target.clone(),
+                delta_time,
+                self.epsilon,
+            );
+            if settled {
+                self.completed_passes = self.completed_passes.saturating_add(1);
+                let repeats = match self.repeat_count {
+                    RepeatCount::Count(count) => self.completed_passes < count,
+                    RepeatCount::Forever => true,
+                };
+                if repeats {
+                    self.start_next_pass();
+                } else {
+                    self.animating = false;
+                    self.record(AnimationEvent::Finished);
+                }
+            }
+        }
+        !self.animating
+    }
+}
+
+/// A stateful animation that is advanced by ticks, which lets animations of
+/// different types be driven together, as by an
+/// [`AnimationGroup`](crate::AnimationGroup).
+pub trait Animation {
+    /// Advances the animation by `delta_time`, and returns whether it has
+    /// finished.
+    fn tick(&mut self, delta_time: f64) -> bool;
+
+    /// Returns whether the animation is still in progress.
+    fn is_animating(&self) -> bool;
+}
+
+impl<V> Animation for SpringAnimation<V>
+where
+    V: VectorArithmetic,
+{
+    #[inline]
+    fn tick(&mut self, delta_time: f64) -> bool {
+        SpringAnimation::tick(self, delta_time)
+    }
+
+    #[inline]
+    fn is_animating(&self) -> bool {
+        SpringAnimation::is_animating(self)
+    }
+}
+
+impl<A> Animation for Box<A>
+where
+    A: Animation + ?Sized,
+{
+    #[inline]
+    fn tick(&mut self, delta_time: f64) -> bool {
+        (**self).tick(delta_time)
+    }
+
+    #[inline]
+    fn is_animating(&self) -> bool {
+        (**self).is_animating()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_spring_is_used() {
+        let _lock = crate::default_spring::TEST_LOCK.lock().unwrap();
+        let spring = Spring::with_duration_bounce(1.5, 0.0);
+        crate::set_default_spring(spring);
+        let animation = SpringAnimation::with_default_spring(0.0);
+        crate::reset_default_spring();
+        assert_eq!(animation.spring, spring);
+    }
+}
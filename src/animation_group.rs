@@ -0,0 +1,111 @@
+use crate::animation::Animation;
+
+/// Animations that are ticked together and finish together, such as the
+/// position, scale and opacity of a card, each with its own spring.
+///
+/// The members can be a tuple of animations of different types, which keeps
+/// each member accessible with its own type through
+/// [`members`](Self::members), or a `Vec` of animations of a single type,
+/// including boxed `dyn Animation`s. Groups are animations themselves, so
+/// they can be nested.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationGroup<T> {
+    pub members: T,
+}
+
+impl<T> AnimationGroup<T>
+where
+    T: AnimationMembers,
+{
+    #[inline]
+    pub const fn new(members: T) -> Self {
+        Self { members }
+    }
+
+    /// Advances every member by `delta_time`, and returns whether all of
+    /// them have finished.
+    #[inline]
+    pub fn tick(&mut self, delta_time: f64) -> bool {
+        self.members.tick_all(delta_time)
+    }
+
+    /// Returns whether any member is still in progress.
+    #[inline]
+    pub fn is_animating(&self) -> bool {
+        self.members.any_animating()
+    }
+}
+
+impl<T> Animation for AnimationGroup<T>
+where
+    T: AnimationMembers,
+{
+    #[inline]
+    fn tick(&mut self, delta_time: f64) -> bool {
+        AnimationGroup::tick(self, delta_time)
+    }
+
+    #[inline]
+    fn is_animating(&self) -> bool {
+        AnimationGroup::is_animating(self)
+    }
+}
+
+/// A collection of animations that can be the members of an
+/// [`AnimationGroup`].
+pub trait AnimationMembers {
+    /// Advances every member by `delta_time`, and returns whether all of
+    /// them have finished.
+    fn tick_all(&mut self, delta_time: f64) -> bool;
+
+    /// Returns whether any member is still in progress.
+    fn any_animating(&self) -> bool;
+}
+
+impl<A> AnimationMembers for Vec<A>
+where
+    A: Animation,
+{
+    fn tick_all(&mut self, delta_time: f64) -> bool {
+        let mut finished = true;
+        for member in self {
+            finished &= member.tick(delta_time);
+        }
+        finished
+    }
+
+    fn any_animating(&self) -> bool {
+        self.iter().any(Animation::is_animating)
+    }
+}
+
+macro_rules! animation_members_tuple_impl {
+    ($(($($member:ident $index:tt),+))*) => ($(
+        impl<$($member),+> AnimationMembers for ($($member,)+)
+        where
+            $($member: Animation,)+
+        {
+            fn tick_all(&mut self, delta_time: f64) -> bool {
+                // Every member is ticked, even once one is found unfinished.
+                let finished = [$(self.$index.tick(delta_time)),+];
+                finished.into_iter().all(|finished| finished)
+            }
+
+            fn any_animating(&self) -> bool {
+                false $(|| self.$index.is_animating())+
+            }
+        }
+    )*)
+}
+
+animation_members_tuple_impl! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+}
@@ -0,0 +1,232 @@
+use crate::animation::{Animation, SpringAnimation};
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// One stage of an [`AnimationSequence`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceSegment<V> {
+    /// The spring that moves the value during this segment.
+    pub spring: Spring,
+    /// The value this segment moves towards.
+    pub target: V,
+    /// The progress of the previous segment at which this one starts, where 1
+    /// waits for the previous segment to finish and smaller values overlap
+    /// the two.
+    pub start_progress: f64,
+}
+
+/// A value moved through a series of targets, one segment after another, such
+/// as an element that lifts, moves and then settles.
+///
+/// Each segment can start once the previous one finishes, or once it has
+/// covered a fraction of its distance. A segment takes over the value and
+/// velocity wherever the previous one left them, so the motion stays smooth
+/// across segments.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationSequence<V> {
+    animation: SpringAnimation<V>,
+    segments: Vec<SequenceSegment<V>>,
+    /// The index of the segment in progress, or `None` before the first
+    /// tick.
+    current: Option<usize>,
+    /// The value at which the segment in progress started.
+    origin: V,
+}
+
+impl<V> AnimationSequence<V>
+where
+    V: VectorArithmetic,
+{
+    /// Creates an empty sequence at rest at `value`.
+    pub fn new(value: V) -> Self {
+        Self {
+            animation: SpringAnimation::new(Spring::default(), value.clone()),
+            segments: Vec::new(),
+            current: None,
+            origin: value,
+        }
+    }
+
+    /// Appends a segment that moves to `target` with `spring` once the
+    /// previous segment finishes.
+    #[inline]
+    pub fn then(self, spring: Spring, target: V) -> Self {
+        self.then_at_progress(spring, target, 1.0)
+    }
+
+    /// Appends a segment that moves to `target` with `spring` once the
+    /// previous segment has covered `progress` of its distance, between 0
+    /// and 1. A progress of 1 or more waits for the previous segment to
+    /// finish, as in [`then`](Self::then).
+    ///
+    /// A progress of 0.8 overlaps the two segments by the last fifth of the
+    /// previous one's distance.
+    pub fn then_at_progress(mut self, spring: Spring, target: V, progress: f64) -> Self {
+        self.push(SequenceSegment {
+            spring,
+            target,
+            start_progress: progress,
+        });
+        self
+    }
+
+    /// Appends a segment.
+    #[inline]
+    pub fn push(&mut self, segment: SequenceSegment<V>) {
+        self.segments.push(segment);
+    }
+
+    /// The segments, in the order they play.
+    #[inline]
+    pub fn segments(&self) -> &[SequenceSegment<V>] {
+        &self.segments
+    }
+
+    /// The index of the segment in progress, or of the last segment once the
+    /// sequence has finished, or `None` before the sequence has started.
+    #[inline]
+    pub fn current_segment(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// The current value.
+    #[inline]
+    pub fn value(&self) -> &V {
+        self.animation.value()
+    }
+
+    /// The current velocity.
+    #[inline]
+    pub fn velocity(&self) -> &V {
+        self.animation.velocity()
+    }
+
+    /// Returns whether the sequence has segments left to play.
+    pub fn is_animating(&self) -> bool {
+        match self.current {
+            None => !self.segments.is_empty(),
+            Some(index) => index + 1 < self.segments.len() || self.animation.is_animating(),
+        }
+    }
+
+    /// The fraction of its distance that the segment in progress has
+    /// covered, measured along the direction from its start to its target.
+    ///
+    /// Segments that do not move the value are complete from the start.
+    pub fn segment_progress(&self) -> f64 {
+        let distance = self.animation.target().clone() - self.origin.clone();
+        let distance_squared = distance.magnitude_squared();
+        if distance_squared == 0.0 {
+            return 1.0;
+        }
+        (self.animation.value().clone() - self.origin.clone()).dot(&distance) / distance_squared
+    }
+
+    /// Starts the segment at `index` from the current value and velocity.
+    fn start_segment(&mut self, index: usize) {
+        let segment = &self.segments[index];
+        self.animation.spring = segment.spring;
+        self.animation.set_target(segment.target.clone());
+        self.origin = self.animation.value().clone();
+        self.current = Some(index);
+    }
+
+    /// Advances the sequence by `delta_time`, starting segments as their
+    /// turn comes, and returns whether the last segment has finished.
+    pub fn tick(&mut self, delta_time: f64) -> bool {
+        if self.current.is_none() {
+            if self.segments.is_empty() {
+                return true;
+            }
+            self.start_segment(0);
+        }
+        let finished = self.animation.tick(delta_time);
+
+        // Start every following segment whose trigger has been reached,
+        // which may be several if they start at no progress.
+        while let Some(index) = self.current {
+            let Some(next) = self.segments.get(index + 1) else {
+                break;
+            };
+            // A bouncy segment covers its whole distance as soon as it first
+            // overshoots, so only the end of its motion starts the next one.
+            let triggered = !self.animation.is_animating()
+                || (next.start_progress < 1.0 && self.segment_progress() >= next.start_progress);
+            if !triggered {
+                break;
+            }
+            self.start_segment(index + 1);
+        }
+        finished && !self.is_animating()
+    }
+}
+
+impl<V> Animation for AnimationSequence<V>
+where
+    V: VectorArithmetic,
+{
+    #[inline]
+    fn tick(&mut self, delta_time: f64) -> bool {
+        AnimationSequence::tick(self, delta_time)
+    }
+
+    #[inline]
+    fn is_animating(&self) -> bool {
+        AnimationSequence::is_animating(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: f64 = 1.0 / 120.0;
+
+    #[test]
+    fn then_waits_for_an_underdamped_segment_to_settle() {
+        let mut sequence = AnimationSequence::new(0.0)
+            .then(Spring::bouncy(), 1.0)
+            .then(Spring::smooth(), 2.0);
+        let mut time = 0.0;
+        while sequence.current_segment() != Some(1) {
+            assert!(!sequence.tick(FRAME));
+            time += FRAME;
+            assert!(time < 10.0, "the second segment never started");
+        }
+        // The first segment overshoots 1 after about a quarter of a second,
+        // long before it comes to rest there.
+        assert!(time > 0.5, "the second segment started at {time}s");
+        assert_eq!(*sequence.value(), 1.0);
+        assert_eq!(*sequence.velocity(), 0.0);
+    }
+
+    #[test]
+    fn partial_progress_overlaps_segments() {
+        let mut sequence = AnimationSequence::new(0.0)
+            .then(Spring::bouncy(), 1.0)
+            .then_at_progress(Spring::smooth(), 2.0, 0.5);
+        while sequence.current_segment() != Some(1) {
+            sequence.tick(FRAME);
+        }
+        assert!(*sequence.value() >= 0.5 && *sequence.value() < 1.0);
+        assert!(*sequence.velocity() > 0.0);
+    }
+
+    #[test]
+    fn finishes_at_the_last_target() {
+        let mut sequence = AnimationSequence::new(0.0)
+            .then(Spring::snappy(), 1.0)
+            .then(Spring::bouncy(), -1.0)
+            .then(Spring::smooth(), 0.5);
+        let mut ticks = 0;
+        while !sequence.tick(FRAME) {
+            ticks += 1;
+            assert!(ticks < 10_000);
+        }
+        assert_eq!(sequence.current_segment(), Some(2));
+        assert_eq!(*sequence.value(), 0.5);
+        assert!(!sequence.is_animating());
+    }
+}
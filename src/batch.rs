@@ -0,0 +1,515 @@
+use std::cell::Cell;
+use std::ops::{Add, Div, Mul, Neg, Range, Sub};
+
+use crate::spring::Spring;
+
+/// A collection of springs stored as a structure of arrays, for animating many
+/// independent scalar values with distinct springs.
+///
+/// Each parameter of the springs is stored in its own contiguous array, and
+/// the evaluation methods process every spring in a single pass over slices
+/// of targets, values, and velocities indexed like the batch itself.
+///
+/// The springs are evaluated in groups with the same kind of damping, so the
+/// motion of each group is computed without branching. With the `simd`
+/// feature, each group of 4 springs is evaluated at once with the `wide` SIMD
+/// types.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SpringBatch {
+    angular_frequencies: Vec<f64>,
+    decay_constants: Vec<f64>,
+    masses: Vec<f64>,
+}
+
+impl SpringBatch {
+    /// Creates an empty batch.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            angular_frequencies: Vec::new(),
+            decay_constants: Vec::new(),
+            masses: Vec::new(),
+        }
+    }
+
+    /// Creates an empty batch with space for at least `capacity` springs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            angular_frequencies: Vec::with_capacity(capacity),
+            decay_constants: Vec::with_capacity(capacity),
+            masses: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a spring to the batch.
+    pub fn push(&mut self, spring: Spring) {
+        self.angular_frequencies.push(spring.angular_frequency);
+        self.decay_constants.push(spring.decay_constant);
+        self.masses.push(spring.mass);
+    }
+
+    /// Returns the spring at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Spring> {
+        (index < self.len()).then(|| self.spring(index))
+    }
+
+    /// Replaces the spring at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, spring: Spring) {
+        self.angular_frequencies[index] = spring.angular_frequency;
+        self.decay_constants[index] = spring.decay_constant;
+        self.masses[index] = spring.mass;
+    }
+
+    /// Removes every spring from the batch.
+    pub fn clear(&mut self) {
+        self.angular_frequencies.clear();
+        self.decay_constants.clear();
+        self.masses.clear();
+    }
+
+    /// The number of springs in the batch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.angular_frequencies.len()
+    }
+
+    /// Returns whether the batch contains no springs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.angular_frequencies.is_empty()
+    }
+
+    /// Returns an iterator over the springs in the batch.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Spring> + '_ {
+        (0..self.len()).map(|index| self.spring(index))
+    }
+
+    /// The angular frequencies of the springs, in order.
+    #[inline]
+    pub fn angular_frequencies(&self) -> &[f64] {
+        &self.angular_frequencies
+    }
+
+    /// The decay constants of the springs, in order.
+    #[inline]
+    pub fn decay_constants(&self) -> &[f64] {
+        &self.decay_constants
+    }
+
+    /// The masses of the springs, in order.
+    #[inline]
+    pub fn masses(&self) -> &[f64] {
+        &self.masses
+    }
+
+    /// Calculates the value of every spring at a given time given its target
+    /// amount of change, writing the results to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn values(
+        &self,
+        targets: &[f64],
+        initial_velocities: &[f64],
+        time: f64,
+        output: &mut [f64],
+    ) {
+        self.assert_len(&[targets.len(), initial_velocities.len(), output.len()]);
+        self.evaluate(
+            0..self.len(),
+            time,
+            |index| (targets[index], initial_velocities[index]),
+            |index, value, _| output[index] = value,
+        );
+    }
+
+    /// Calculates the velocity of every spring at a given time given its
+    /// target amount of change, writing the results to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn velocities(
+        &self,
+        targets: &[f64],
+        initial_velocities: &[f64],
+        time: f64,
+        output: &mut [f64],
+    ) {
+        self.assert_len(&[targets.len(), initial_velocities.len(), output.len()]);
+        self.evaluate(
+            0..self.len(),
+            time,
+            |index| (targets[index], initial_velocities[index]),
+            |index, _, velocity| output[index] = velocity,
+        );
+    }
+
+    /// Updates the current value and velocity of every spring, as
+    /// [`Spring::update`] does for a single one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn update(
+        &self,
+        values: &mut [f64],
+        velocities: &mut [f64],
+        targets: &[f64],
+        delta_time: f64,
+    ) {
+        self.assert_len(&[values.len(), velocities.len(), targets.len()]);
+        self.update_range(0..self.len(), values, velocities, targets, delta_time);
+    }
+
+    /// Updates the springs in `range`, whose values and velocities are the
+    /// slices, and whose targets are indexed like the batch.
+    pub(crate) fn update_range(
+        &self,
+        range: Range<usize>,
+        values: &mut [f64],
+        velocities: &mut [f64],
+        targets: &[f64],
+        delta_time: f64,
+    ) {
+        let start = range.start;
+        let values = Cell::from_mut(values).as_slice_of_cells();
+        let velocities = Cell::from_mut(velocities).as_slice_of_cells();
+        self.evaluate(
+            range,
+            delta_time,
+            |index| {
+                let offset = index - start;
+                (
+                    targets[index] - values[offset].get(),
+                    velocities[offset].get(),
+                )
+            },
+            |index, value, velocity| {
+                let offset = index - start;
+                values[offset].set(values[offset].get() + value);
+                velocities[offset].set(velocity);
+            },
+        );
+    }
+
+    /// Evaluates the motion of the springs in `range` at `time`, reading the
+    /// target amount of change and initial velocity of each spring from
+    /// `input` and passing its value and velocity to `output`.
+    ///
+    /// Springs are collected into a group for each kind of damping, and each
+    /// group is evaluated once it fills the lanes of a SIMD vector. `output`
+    /// is called for each spring after `input`, but not in order.
+    pub(crate) fn evaluate(
+        &self,
+        range: Range<usize>,
+        time: f64,
+        input: impl Fn(usize) -> (f64, f64),
+        mut output: impl FnMut(usize, f64, f64),
+    ) {
+        #[cfg(feature = "simd")]
+        self.evaluate_lanes::<wide::f64x4, 4>(range, time, &input, &mut output);
+        #[cfg(not(feature = "simd"))]
+        self.evaluate_lanes::<f64, 1>(range, time, &input, &mut output);
+    }
+
+    fn evaluate_lanes<L, const N: usize>(
+        &self,
+        range: Range<usize>,
+        time: f64,
+        input: &impl Fn(usize) -> (f64, f64),
+        output: &mut impl FnMut(usize, f64, f64),
+    ) where
+        L: Lanes<N>,
+    {
+        let mut groups = [[0; N]; 3];
+        let mut lengths = [0; 3];
+        for index in range {
+            let regime = Regime::of(self.angular_frequencies[index]);
+            let group = &mut groups[regime as usize];
+            let length = &mut lengths[regime as usize];
+            group[*length] = index;
+            *length += 1;
+            if *length == N {
+                self.evaluate_group::<L, N>(regime, group, time, input, output);
+                *length = 0;
+            }
+        }
+        for regime in [Regime::Underdamped, Regime::Critical, Regime::Overdamped] {
+            let indices = &groups[regime as usize][..lengths[regime as usize]];
+            if !indices.is_empty() {
+                self.evaluate_group::<L, N>(regime, indices, time, input, output);
+            }
+        }
+    }
+
+    /// Evaluates up to `N` springs with the same kind of damping at once,
+    /// filling any unused lanes with the first spring.
+    fn evaluate_group<L, const N: usize>(
+        &self,
+        regime: Regime,
+        indices: &[usize],
+        time: f64,
+        input: &impl Fn(usize) -> (f64, f64),
+        output: &mut impl FnMut(usize, f64, f64),
+    ) where
+        L: Lanes<N>,
+    {
+        let index = |lane: usize| indices.get(lane).copied().unwrap_or(indices[0]);
+        let inputs: [(f64, f64); N] = std::array::from_fn(|lane| input(index(lane)));
+        let (values, velocities) = motion(
+            regime,
+            L::from_array(std::array::from_fn(|lane| {
+                self.angular_frequencies[index(lane)]
+            })),
+            L::from_array(std::array::from_fn(|lane| {
+                self.decay_constants[index(lane)]
+            })),
+            L::from_array(inputs.map(|(target, _)| target)),
+            L::from_array(inputs.map(|(_, initial_velocity)| initial_velocity)),
+            L::splat(time),
+        );
+        let (values, velocities) = (values.to_array(), velocities.to_array());
+        for (lane, &index) in indices.iter().enumerate() {
+            output(index, values[lane], velocities[lane]);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn spring(&self, index: usize) -> Spring {
+        Spring::new(
+            self.angular_frequencies[index],
+            self.decay_constants[index],
+            self.masses[index],
+        )
+    }
+
+    pub(crate) fn assert_len(&self, lengths: &[usize]) {
+        for &length in lengths {
+            assert_eq!(length, self.len(), "slice length does not match the batch");
+        }
+    }
+}
+
+impl FromIterator<Spring> for SpringBatch {
+    fn from_iter<I: IntoIterator<Item = Spring>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        batch.extend(iter);
+        batch
+    }
+}
+
+impl Extend<Spring> for SpringBatch {
+    fn extend<I: IntoIterator<Item = Spring>>(&mut self, iter: I) {
+        for spring in iter {
+            self.push(spring);
+        }
+    }
+}
+
+/// The kind of damping of a spring, which decides the form of its motion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Regime {
+    Underdamped,
+    Critical,
+    Overdamped,
+}
+
+impl Regime {
+    #[inline]
+    fn of(angular_frequency: f64) -> Self {
+        if angular_frequency > 0.0 {
+            Self::Underdamped
+        } else if angular_frequency < 0.0 {
+            Self::Overdamped
+        } else {
+            Self::Critical
+        }
+    }
+}
+
+/// A vector of `N` lanes of `f64` that the motion of `N` springs can be
+/// computed in at once.
+pub(crate) trait Lanes<const N: usize>:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn splat(value: f64) -> Self;
+    fn from_array(lanes: [f64; N]) -> Self;
+    fn to_array(self) -> [f64; N];
+    fn exp(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+}
+
+impl Lanes<1> for f64 {
+    #[inline]
+    fn splat(value: f64) -> Self {
+        value
+    }
+
+    #[inline]
+    fn from_array([lane]: [f64; 1]) -> Self {
+        lane
+    }
+
+    #[inline]
+    fn to_array(self) -> [f64; 1] {
+        [self]
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        f64::sin_cos(self)
+    }
+}
+
+/// Returns the value and velocity of springs with the same kind of damping,
+/// as [`Spring::value`] and [`Spring::velocity`] do up to rounding.
+///
+/// The remaining distance is written, as in `SpringEvaluator`, as two
+/// coefficients weighted by functions of time.
+#[inline]
+fn motion<L, const N: usize>(
+    regime: Regime,
+    frequency: L,
+    decay: L,
+    target: L,
+    initial_velocity: L,
+    time: L,
+) -> (L, L)
+where
+    L: Lanes<N>,
+{
+    let ([first, second], [first_weight, second_weight, first_rate, second_rate]) = match regime {
+        Regime::Underdamped => {
+            let envelope = (-decay * time).exp();
+            let (sin, cos) = (frequency * time).sin_cos();
+            (
+                [target, (target * decay - initial_velocity) / frequency],
+                [
+                    envelope * cos,
+                    envelope * sin,
+                    -envelope * (decay * cos + frequency * sin),
+                    envelope * (frequency * cos - decay * sin),
+                ],
+            )
+        }
+        Regime::Critical => {
+            let envelope = (-decay * time).exp();
+            (
+                [target, target * decay - initial_velocity],
+                [
+                    envelope,
+                    envelope * time,
+                    -decay * envelope,
+                    envelope * (L::splat(1.0) - decay * time),
+                ],
+            )
+        }
+        Regime::Overdamped => {
+            let (slow_rate, fast_rate) = (-decay - frequency, frequency - decay);
+            let slow = -(initial_velocity + target * fast_rate) / (slow_rate - fast_rate);
+            let (slow_weight, fast_weight) = ((slow_rate * time).exp(), (fast_rate * time).exp());
+            (
+                [slow, target - slow],
+                [
+                    slow_weight,
+                    fast_weight,
+                    slow_rate * slow_weight,
+                    fast_rate * fast_weight,
+                ],
+            )
+        }
+    };
+    (
+        target - first * first_weight - second * second_weight,
+        -(first * first_rate + second * second_rate),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch() -> SpringBatch {
+        [
+            Spring::smooth(),
+            Spring::bouncy(),
+            Spring::with_duration_bounce(0.4, -0.5),
+            Spring::snappy(),
+            Spring::with_duration_bounce(0.8, -0.2),
+            Spring::interactive(),
+            Spring::with_duration_bounce(0.3, 0.0),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn evaluation_matches_each_spring() {
+        let batch = batch();
+        let targets: Vec<f64> = (0..batch.len()).map(|index| index as f64 - 2.0).collect();
+        let initial_velocities: Vec<f64> = (0..batch.len()).map(|index| index as f64).collect();
+        let (mut values, mut velocities) = (vec![0.0; batch.len()], vec![0.0; batch.len()]);
+        for time in [0.0, 0.05, 0.3, 1.5] {
+            batch.values(&targets, &initial_velocities, time, &mut values);
+            batch.velocities(&targets, &initial_velocities, time, &mut velocities);
+            for (index, spring) in batch.iter().enumerate() {
+                let (target, initial_velocity) = (targets[index], initial_velocities[index]);
+                let value = spring.value(target, initial_velocity, time);
+                let velocity = spring.velocity(target, initial_velocity, time);
+                assert!((values[index] - value).abs() < 1e-12);
+                assert!((velocities[index] - velocity).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn update_matches_each_spring() {
+        let batch = batch();
+        let targets = vec![1.0; batch.len()];
+        let (mut values, mut velocities) = (vec![0.0; batch.len()], vec![3.0; batch.len()]);
+        let mut expected: Vec<(f64, f64)> = vec![(0.0, 3.0); batch.len()];
+        for _ in 0..30 {
+            batch.update(&mut values, &mut velocities, &targets, 1.0 / 60.0);
+            for (spring, (value, velocity)) in batch.iter().zip(&mut expected) {
+                spring.update(value, velocity, 1.0, 1.0 / 60.0);
+            }
+        }
+        for (index, (value, velocity)) in expected.into_iter().enumerate() {
+            assert!((values[index] - value).abs() < 1e-12);
+            assert!((velocities[index] - velocity).abs() < 1e-10);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_evaluation_matches_sequential() {
+        let springs: Vec<Spring> = batch().iter().collect();
+        let batch: SpringBatch = springs.into_iter().cycle().take(5000).collect();
+        let targets: Vec<f64> = (0..batch.len()).map(|index| (index % 7) as f64).collect();
+        let initial_velocities = vec![0.5; batch.len()];
+        let mut sequential = vec![0.0; batch.len()];
+        let mut parallel = vec![0.0; batch.len()];
+        batch.values(&targets, &initial_velocities, 0.2, &mut sequential);
+        batch.par_values(&targets, &initial_velocities, 0.2, &mut parallel);
+        assert_eq!(sequential, parallel);
+        batch.velocities(&targets, &initial_velocities, 0.2, &mut sequential);
+        batch.par_velocities(&targets, &initial_velocities, 0.2, &mut parallel);
+        assert_eq!(sequential, parallel);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::geometry::Point;
+use crate::spring::Spring;
+use crate::subdivision;
+
+/// A cubic Bézier curve in the plane of time, along the x axis, and value,
+/// along the y axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezierSegment {
+    pub start: Point,
+    pub control1: Point,
+    pub control2: Point,
+    pub end: Point,
+}
+
+impl CubicBezierSegment {
+    #[inline]
+    pub const fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Returns the point on the curve at parameter `t` between 0 and 1.
+    pub fn point(&self, t: f64) -> Point {
+        let s = 1.0 - t;
+        let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+        Point::new(
+            a * self.start.x + b * self.control1.x + c * self.control2.x + d * self.end.x,
+            a * self.start.y + b * self.control1.y + c * self.control2.y + d * self.end.y,
+        )
+    }
+
+    /// Returns the control points `[x1, y1, x2, y2]` relative to the box
+    /// spanned by the start and end points, as taken by CSS `cubic-bezier()`
+    /// and the easing of keyframe-based tools.
+    ///
+    /// Returns `None` if the segment has no duration or no change in value.
+    pub fn normalized_control_points(&self) -> Option<[f64; 4]> {
+        let width = self.end.x - self.start.x;
+        let height = self.end.y - self.start.y;
+        if width == 0.0 || height == 0.0 {
+            return None;
+        }
+        Some([
+            (self.control1.x - self.start.x) / width,
+            (self.control1.y - self.start.y) / height,
+            (self.control2.x - self.start.x) / width,
+            (self.control2.y - self.start.y) / height,
+        ])
+    }
+
+    /// The segment that matches the value and slope at both ends of the time
+    /// range, whose time is linear in the curve parameter.
+    fn hermite(start: Point, start_slope: f64, end: Point, end_slope: f64) -> Self {
+        let third = (end.x - start.x) / 3.0;
+        Self::new(
+            start,
+            Point::new(start.x + third, start.y + start_slope * third),
+            Point::new(end.x - third, end.y - end_slope * third),
+            end,
+        )
+    }
+}
+
+impl Spring {
+    /// Approximates the motion of the spring, starting at rest and moving
+    /// from 0 to 1, with a sequence of cubic Bézier segments.
+    ///
+    /// The segments cover the settling duration, end at rest at exactly 1 and
+    /// are joined with continuous slopes. Each segment is subdivided until the
+    /// value of the curve is within `tolerance` of the spring at every time.
+    /// Springs without damping never settle, so their curve spans their
+    /// [`duration`](Self::duration) and ends wherever the spring is then.
+    ///
+    /// Tolerances below 10⁻⁹, including zero, negative and NaN tolerances,
+    /// are raised to 10⁻⁹, and at most 65,536 segments are returned.
+    pub fn to_cubic_beziers(&self, tolerance: f64) -> Vec<CubicBezierSegment> {
+        let tolerance = subdivision::tolerance(tolerance);
+        let settling_duration = self.settling_duration_with_velocity(1.0, 0.0, tolerance / 2.0);
+        let settles = settling_duration.is_finite();
+        let duration = if settles {
+            settling_duration
+        } else {
+            self.duration()
+        };
+        let knot = |time: f64| {
+            if settles && time >= duration {
+                (Point::new(duration, 1.0), 0.0)
+            } else {
+                (
+                    Point::new(time, self.value(1.0, 0.0, time)),
+                    self.velocity(1.0, 0.0, time),
+                )
+            }
+        };
+        let segment = |start: &(Point, f64), end: &(Point, f64)| {
+            CubicBezierSegment::hermite(start.0, start.1, end.0, end.1)
+        };
+
+        let knots = subdivision::subdivide(duration, tolerance, knot, |start, end, amount| {
+            let point = segment(start, end).point(amount);
+            (point.y - self.value(1.0, 0.0, point.x)).abs()
+        });
+        knots
+            .windows(2)
+            .map(|pair| segment(&pair[0], &pair[1]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_stay_within_the_tolerance() {
+        for spring in [Spring::smooth(), Spring::snappy(), Spring::bouncy()] {
+            let segments = spring.to_cubic_beziers(1e-3);
+            assert_eq!(segments[0].start, Point::new(0.0, 0.0));
+            assert_eq!(segments.last().unwrap().end.y, 1.0);
+            for segment in &segments {
+                for sample in 0..=10 {
+                    let point = segment.point(f64::from(sample) / 10.0);
+                    assert!((point.y - spring.value(1.0, 0.0, point.x)).abs() <= 1e-3);
+                }
+            }
+            assert!(segments.windows(2).all(|pair| pair[0].end == pair[1].start));
+        }
+    }
+
+    #[test]
+    fn degenerate_tolerances_are_bounded() {
+        for tolerance in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let segments = Spring::bouncy().to_cubic_beziers(tolerance);
+            assert!(!segments.is_empty());
+            assert!(segments.len() <= subdivision::MAX_INTERVALS);
+            assert!(segments.windows(2).all(|pair| pair[0].end == pair[1].start));
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::error::SpringError;
+use crate::spring::Spring;
+use crate::substep::substeps;
+
+/// How a [`BoundedSpring`] behaves when its value reaches a bound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoundaryPolicy {
+    /// The value stops at the bound, and any velocity towards the outside is
+    /// removed.
+    Clamp,
+    /// The value bounces off the bound, reversing its velocity and scaling it
+    /// by the restitution, where 1 preserves the speed and 0 stops the value as
+    /// [`Clamp`](Self::Clamp) does.
+    Reflect { restitution: f64 },
+    /// The value may pass the bound, but the spring then pulls it back
+    /// towards the bound instead of the target, with a continuous velocity,
+    /// like an overscrolled scroll view.
+    RubberBand,
+}
+
+/// A spring whose value is constrained to a range, as a scroll view is to its
+/// content.
+///
+/// Targets outside the range are clamped into it, and the [`BoundaryPolicy`]
+/// decides what happens when the motion reaches a bound. If `min` is greater
+/// than `max`, values are held at `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundedSpring {
+    pub spring: Spring,
+    pub min: f64,
+    pub max: f64,
+    pub policy: BoundaryPolicy,
+}
+
+impl Spring {
+    /// Returns a version of this spring whose value is constrained to
+    /// `min..=max` according to `policy`.
+    #[inline]
+    pub const fn bounded(self, min: f64, max: f64, policy: BoundaryPolicy) -> BoundedSpring {
+        BoundedSpring::new(self, min, max, policy)
+    }
+}
+
+impl BoundedSpring {
+    /// Creates a bounded spring without validating the bounds, which
+    /// [`try_new`](Self::try_new) does.
+    #[inline]
+    pub const fn new(spring: Spring, min: f64, max: f64, policy: BoundaryPolicy) -> Self {
+        Self {
+            spring,
+            min,
+            max,
+            policy,
+        }
+    }
+
+    /// Creates a bounded spring, validating the bounds.
+    ///
+    /// Returns an error if `min` is greater than `max` or either bound is NaN.
+    /// Infinite bounds leave that side of the range open.
+    pub fn try_new(
+        spring: Spring,
+        min: f64,
+        max: f64,
+        policy: BoundaryPolicy,
+    ) -> Result<Self, SpringError> {
+        if min.is_nan() || max.is_nan() || min > max {
+            return Err(SpringError::InvalidBounds);
+        }
+        Ok(Self::new(spring, min, max, policy))
+    }
+
+    /// Updates the current value and velocity of the spring, applying the
+    /// boundary policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The current value of the spring.
+    /// * `velocity` - The current velocity of the spring.
+    /// * `target` - The target that `value` is moving towards, clamped into
+    ///   the bounds.
+    /// * `delta_time` - The amount of time that has passed since the spring was
+    ///   at the position specified by `value`.
+    pub fn update(&self, value: &mut f64, velocity: &mut f64, target: f64, delta_time: f64) {
+        let target = self.clamp_value(target);
+        match self.policy {
+            BoundaryPolicy::Clamp => {
+                self.spring.update(value, velocity, target, delta_time);
+                self.clamp(value, velocity);
+            }
+            BoundaryPolicy::Reflect { restitution } => {
+                self.spring.update(value, velocity, target, delta_time);
+                let bound = self.clamp_value(*value);
+                if *value != bound {
+                    // Mirror the overshoot back inside the bounds, losing the
+                    // same fraction of it as of the velocity.
+                    *value = bound - (*value - bound) * restitution;
+                    *velocity = -*velocity * restitution;
+                    self.clamp(value, velocity);
+                }
+            }
+            BoundaryPolicy::RubberBand => {
+                let target = if *value > self.max {
+                    self.max
+                } else if *value < self.min {
+                    self.min
+                } else {
+                    target
+                };
+                self.spring.update(value, velocity, target, delta_time);
+            }
+        }
+    }
+
+    /// Updates the current value and velocity of the spring like
+    /// [`update`](Self::update), splitting `delta_time` into substeps no
+    /// longer than `max_substep`.
+    ///
+    /// The boundary policy is only applied at the end of each step, so a long
+    /// frame can carry the value far past a bound before it is clamped,
+    /// reflected or pulled back. Substeps keep the behavior at the bounds the
+    /// same across frame hitches.
+    pub fn update_substepped(
+        &self,
+        value: &mut f64,
+        velocity: &mut f64,
+        target: f64,
+        delta_time: f64,
+        max_substep: f64,
+    ) {
+        let (count, step) = substeps(delta_time, max_substep);
+        for _ in 0..count {
+            self.update(value, velocity, target, step);
+        }
+    }
+
+    /// Returns whether `value` lies within the bounds.
+    #[inline]
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    /// Clamps `value` into the bounds, without panicking on invalid bounds as
+    /// `f64::clamp` does.
+    #[inline]
+    fn clamp_value(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Clamps `value` into the bounds, removing any velocity that points
+    /// outside them at a bound.
+    fn clamp(&self, value: &mut f64, velocity: &mut f64) {
+        if *value >= self.max {
+            *value = self.max;
+            *velocity = velocity.min(0.0);
+        } else if *value <= self.min {
+            *value = self.min;
+            *velocity = velocity.max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: f64 = 1.0 / 60.0;
+
+    /// Runs `spring` from 0 with a velocity of 20 towards a target of 1, and
+    /// returns the values of each frame.
+    fn run(spring: BoundedSpring) -> Vec<(f64, f64)> {
+        let (mut value, mut velocity) = (0.0, 20.0);
+        (0..120)
+            .map(|_| {
+                spring.update(&mut value, &mut velocity, 1.0, FRAME);
+                (value, velocity)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clamp_stops_at_the_bound() {
+        let frames = run(Spring::smooth().bounded(0.0, 1.0, BoundaryPolicy::Clamp));
+        assert!(
+            frames
+                .iter()
+                .all(|&(value, _)| (0.0..=1.0).contains(&value))
+        );
+        assert!(
+            frames
+                .iter()
+                .all(|&(value, velocity)| value < 1.0 || velocity <= 0.0)
+        );
+        assert_eq!(frames.last().unwrap().0, 1.0);
+    }
+
+    #[test]
+    fn reflect_bounces_off_the_bound() {
+        let policy = BoundaryPolicy::Reflect { restitution: 0.5 };
+        let frames = run(Spring::smooth().bounded(0.0, 1.0, policy));
+        assert!(
+            frames
+                .iter()
+                .all(|&(value, _)| (0.0..=1.0).contains(&value))
+        );
+        assert!(frames.iter().any(|&(_, velocity)| velocity < 0.0));
+        assert!((frames.last().unwrap().0 - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn rubber_band_overshoots_and_returns() {
+        let frames = run(Spring::smooth().bounded(0.0, 0.5, BoundaryPolicy::RubberBand));
+        assert!(frames.iter().any(|&(value, _)| value > 0.5));
+        assert!((frames.last().unwrap().0 - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn invalid_bounds_are_rejected_without_panicking() {
+        let spring = Spring::smooth();
+        for (min, max) in [(1.0, 0.0), (f64::NAN, 1.0), (0.0, f64::NAN)] {
+            assert_eq!(
+                BoundedSpring::try_new(spring, min, max, BoundaryPolicy::Clamp),
+                Err(SpringError::InvalidBounds),
+            );
+            let bounded = spring.bounded(min, max, BoundaryPolicy::Clamp);
+            let (mut value, mut velocity) = (0.5, 0.0);
+            bounded.update(&mut value, &mut velocity, 2.0, FRAME);
+            bounded.update_substepped(&mut value, &mut velocity, 2.0, 0.5, FRAME);
+        }
+        assert!(BoundedSpring::try_new(spring, 0.0, f64::INFINITY, BoundaryPolicy::Clamp).is_ok());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::spring::Spring;
+
+/// The derived quantities of a [`Spring`], computed together by
+/// [`Spring::characteristics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringCharacteristics {
+    /// The perceptual duration, as returned by [`Spring::duration`].
+    pub duration: f64,
+    /// The bounce, as returned by [`Spring::bounce`].
+    pub bounce: f64,
+    /// The spring stiffness coefficient, as returned by [`Spring::stiffness`].
+    pub stiffness: f64,
+    /// The damping coefficient, as returned by [`Spring::damping`].
+    pub damping: f64,
+    /// The damping ratio, as returned by [`Spring::damping_ratio`].
+    pub damping_ratio: f64,
+    /// The response, as returned by [`Spring::response`].
+    pub response: f64,
+    /// The settling duration, as returned by [`Spring::settling_duration`].
+    pub settling_duration: f64,
+    /// The overshoot, as returned by [`Spring::max_overshoot`].
+    pub overshoot: f64,
+    /// The time of the first peak, as returned by [`Spring::peak_time`].
+    pub peak_time: Option<f64>,
+}
+
+impl Spring {
+    /// Returns the derived quantities of the spring at once.
+    ///
+    /// The quantities are derived from the natural frequency and damping
+    /// ratio, computed once, which is cheaper than calling each accessor
+    /// individually. Only the settling duration is solved on its own.
+    pub fn characteristics(&self) -> SpringCharacteristics {
+        let natural_frequency = self.natural_frequency();
+        // The perceptual duration and the response are the same period.
+        let period = std::f64::consts::TAU / natural_frequency;
+        let damping_ratio = self.decay_constant / natural_frequency;
+        let bounce = if self.angular_frequency >= 0.0 {
+            1.0 - damping_ratio
+        } else {
+            1.0 / damping_ratio - 1.0
+        };
+        let peak_time = self.peak_time();
+        let overshoot = peak_time.map_or(0.0, |peak_time| (-self.decay_constant * peak_time).exp());
+
+        SpringCharacteristics {
+            duration: period,
+            bounce,
+            stiffness: self.mass * natural_frequency * natural_frequency,
+            damping: self.decay_constant * 2.0 * self.mass,
+            damping_ratio,
+            response: period,
+            settling_duration: self.settling_duration(),
+            overshoot,
+            peak_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_accessors() {
+        let springs = [
+            Spring::bouncy(),
+            Spring::smooth(),
+            Spring::with_mass_stiffness_damping(2.0, 100.0, 60.0, true),
+        ];
+        for spring in springs {
+            let characteristics = spring.characteristics();
+            let pairs = [
+                (characteristics.duration, spring.duration()),
+                (characteristics.bounce, spring.bounce()),
+                (characteristics.stiffness, spring.stiffness()),
+                (characteristics.damping, spring.damping()),
+                (characteristics.damping_ratio, spring.damping_ratio()),
+                (characteristics.response, spring.response()),
+                (
+                    characteristics.settling_duration,
+                    spring.settling_duration(),
+                ),
+                (characteristics.overshoot, spring.max_overshoot()),
+            ];
+            for (computed, expected) in pairs {
+                assert!(
+                    (computed - expected).abs() < 1e-9,
+                    "{spring:?}: {computed} != {expected}"
+                );
+            }
+            assert_eq!(characteristics.peak_time, spring.peak_time());
+        }
+    }
+}
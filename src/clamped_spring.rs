@@ -0,0 +1,238 @@
+use crate::spring::Spring;
+use crate::substep::substeps;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The number of steps per search window used to look for the first
+/// crossing of the target.
+const CROSSING_SEARCH_STEPS: u32 = 16;
+const CROSSING_BISECTION_ITERATIONS: u32 = 48;
+
+/// A spring whose motion never goes past its target.
+///
+/// Like UIKit's clamped spring timing, the motion follows the wrapped spring
+/// until it first reaches the target, and then stays there at rest. Progress
+/// is measured along the direction of travel, so the motion stops once the
+/// value has covered the full distance towards the target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampedSpring {
+    pub spring: Spring,
+}
+
+/// Evaluates the motion of a [`ClampedSpring`] towards a fixed target from a
+/// fixed initial velocity, created with [`ClampedSpring::evaluator`].
+///
+/// Finding the time at which the motion first reaches its target takes a
+/// search over the motion, which [`ClampedSpring::value`] and
+/// [`ClampedSpring::velocity`] repeat on every call. The evaluator searches
+/// once, so sampling the same motion many times is much cheaper.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClampedEvaluator<V> {
+    spring: Spring,
+    target: V,
+    initial_velocity: V,
+    crossing_time: f64,
+}
+
+impl Spring {
+    /// Returns a version of this spring that stops at its target instead of
+    /// overshooting it.
+    #[inline]
+    pub const fn clamped(self) -> ClampedSpring {
+        ClampedSpring::new(self)
+    }
+}
+
+impl ClampedSpring {
+    #[inline]
+    pub const fn new(spring: Spring) -> Self {
+        Self { spring }
+    }
+
+    /// Returns an evaluator for the motion of the spring given a target
+    /// amount of change and an initial velocity, which finds the time the
+    /// target is reached once.
+    pub fn evaluator<V>(&self, target: V, initial_velocity: V) -> ClampedEvaluator<V>
+    where
+        V: VectorArithmetic,
+    {
+        let crossing_time = self.first_crossing_time(target.clone(), initial_velocity.clone());
+        ClampedEvaluator {
+            spring: self.spring,
+            target,
+            initial_velocity,
+            crossing_time,
+        }
+    }
+
+    /// Calculates the value of the spring at a given time given a target
+    /// amount of change, stopping at the target once it is first reached.
+    ///
+    /// Each call searches for the time the target is reached. Use an
+    /// [`evaluator`](Self::evaluator) to evaluate the same motion repeatedly.
+    pub fn value<V>(&self, target: V, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        self.evaluator(target, initial_velocity).value(time)
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target
+    /// amount of change. The velocity is zero once the target is reached.
+    ///
+    /// Each call searches for the time the target is reached, as in
+    /// [`value`](Self::value).
+    pub fn velocity<V>(&self, target: V, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        self.evaluator(target, initial_velocity).velocity(time)
+    }
+
+    /// Updates the current value and velocity of a spring, stopping at the
+    /// target if it would be reached or passed during this step.
+    pub fn update<V>(&self, value: &mut V, velocity: &mut V, target: V, delta_time: f64)
+    where
+        V: VectorArithmetic,
+    {
+        let delta = target.clone() - value.clone();
+        self.spring
+            .update(value, velocity, target.clone(), delta_time);
+
+        let remaining = target.clone() - value.clone();
+        if delta.magnitude_squared() > 0.0 && remaining.dot(&delta) <= 0.0 {
+            *value = target;
+            *velocity = V::ZERO;
+        }
+    }
+
+    /// Updates the current value and velocity of a spring like
+    /// [`update`](Self::update), splitting `delta_time` into substeps no
+    /// longer than `max_substep`.
+    ///
+    /// A long frame can carry the spring past the target and back within a
+    /// single step, which [`update`](Self::update) cannot detect. Substeps
+    /// keep the motion stopping at the target across frame hitches.
+    pub fn update_substepped<V>(
+        &self,
+        value: &mut V,
+        velocity: &mut V,
+        target: V,
+        delta_time: f64,
+        max_substep: f64,
+    ) where
+        V: VectorArithmetic,
+    {
+        let (count, step) = substeps(delta_time, max_substep);
+        for _ in 0..count {
+            self.update(value, velocity, target.clone(), step);
+        }
+    }
+
+    /// The first time at which the motion covers the full distance to
+    /// `target`, or infinity if it never does.
+    fn first_crossing_time<V>(&self, target: V, initial_velocity: V) -> f64
+    where
+        V: VectorArithmetic,
+    {
+        let distance_squared = target.magnitude_squared();
+        if distance_squared == 0.0 {
+            return 0.0;
+        }
+        // The remaining fraction of the distance, which becomes zero at the
+        // crossing.
+        let remaining = |time: f64| {
+            1.0 - self
+                .spring
+                .value(target.clone(), initial_velocity.clone(), time)
+                .dot(&target)
+                / distance_squared
+        };
+
+        // Search half an oscillation at a time, or four time constants of the
+        // slower mode for springs that do not oscillate.
+        let spring = &self.spring;
+        let search_duration = if spring.angular_frequency > 0.0 {
+            std::f64::consts::PI / spring.angular_frequency
+        } else {
+            4.0 / (spring.decay_constant + spring.angular_frequency)
+        };
+        if !search_duration.is_finite() || search_duration <= 0.0 {
+            return f64::INFINITY;
+        }
+
+        // An initial velocity away from the target can delay the crossing by
+        // a few oscillations, so search over several windows.
+        let step = search_duration / CROSSING_SEARCH_STEPS as f64;
+        let mut previous = 0.0;
+        for index in 1..=CROSSING_SEARCH_STEPS * 4 {
+            let time = step * index as f64;
+            if remaining(time) <= 0.0 {
+                let (mut low, mut high) = (previous, time);
+                for _ in 0..CROSSING_BISECTION_ITERATIONS {
+                    let middle = 0.5 * (low + high);
+                    if remaining(middle) <= 0.0 {
+                        high = middle;
+                    } else {
+                        low = middle;
+                    }
+                }
+                return high;
+            }
+            previous = time;
+        }
+        f64::INFINITY
+    }
+}
+
+impl<V> ClampedEvaluator<V>
+where
+    V: VectorArithmetic,
+{
+    /// The time at which the motion first reaches the target, or infinity if
+    /// it never does.
+    #[inline]
+    pub fn crossing_time(&self) -> f64 {
+        self.crossing_time
+    }
+
+    /// The value at `time`, which is the target once it has been reached.
+    pub fn value(&self, time: f64) -> V {
+        if time >= self.crossing_time {
+            self.target.clone()
+        } else {
+            self.spring
+                .value(self.target.clone(), self.initial_velocity.clone(), time)
+        }
+    }
+
+    /// The velocity at `time`, which is zero once the target has been
+    /// reached.
+    pub fn velocity(&self, time: f64) -> V {
+        if time >= self.crossing_time {
+            V::ZERO
+        } else {
+            self.spring
+                .velocity(self.target.clone(), self.initial_velocity.clone(), time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluator_matches_the_direct_evaluation() {
+        let clamped = Spring::bouncy().clamped();
+        let evaluator = clamped.evaluator(1.0, 0.0);
+        let crossing_time = evaluator.crossing_time();
+        assert!(crossing_time > 0.0 && crossing_time.is_finite());
+        for time in [0.0, 0.1, crossing_time, 0.5, 2.0] {
+            assert_eq!(evaluator.value(time), clamped.value(1.0, 0.0, time));
+            assert_eq!(evaluator.velocity(time), clamped.velocity(1.0, 0.0, time));
+            assert!(evaluator.value(time) <= 1.0);
+        }
+        assert_eq!(evaluator.value(crossing_time), 1.0);
+    }
+}
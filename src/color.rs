@@ -0,0 +1,105 @@
+use crate::vector_arithmetic::componentwise_vector_arithmetic_impl;
+
+/// A color with linear-light, premultiplied-alpha components.
+///
+/// Interpolating premultiplied components keeps the color of a fading layer
+/// stable: a transparent color contributes nothing, so animating from a
+/// transparent red to an opaque blue never flashes through a dark or
+/// desaturated intermediate the way straight-alpha sRGB interpolation does.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba {
+    /// The red component, premultiplied by `alpha`.
+    pub red: f64,
+    /// The green component, premultiplied by `alpha`.
+    pub green: f64,
+    /// The blue component, premultiplied by `alpha`.
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Rgba {
+    /// A fully transparent color.
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates a color from linear components that are already premultiplied
+    /// by `alpha`.
+    #[inline]
+    pub const fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Creates a color from linear components with straight (unpremultiplied)
+    /// alpha.
+    #[inline]
+    pub fn from_linear(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self::new(red * alpha, green * alpha, blue * alpha, alpha)
+    }
+
+    /// Creates a color from 8-bit sRGB components with straight alpha.
+    pub fn from_srgb8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self::from_linear(
+            srgb_to_linear(red as f64 / 255.0),
+            srgb_to_linear(green as f64 / 255.0),
+            srgb_to_linear(blue as f64 / 255.0),
+            alpha as f64 / 255.0,
+        )
+    }
+
+    /// Returns the linear components with straight (unpremultiplied) alpha.
+    ///
+    /// The color components of a fully transparent color are zero.
+    pub fn to_linear(&self) -> [f64; 4] {
+        if self.alpha <= 0.0 {
+            return [0.0; 4];
+        }
+        [
+            self.red / self.alpha,
+            self.green / self.alpha,
+            self.blue / self.alpha,
+            self.alpha,
+        ]
+    }
+
+    /// Returns the 8-bit sRGB components with straight alpha.
+    ///
+    /// Components are clamped to the displayable range, so an overshooting
+    /// spring saturates instead of wrapping.
+    pub fn to_srgb8(&self) -> [u8; 4] {
+        let [red, green, blue, alpha] = self.to_linear();
+        let quantize = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [
+            quantize(linear_to_srgb(red)),
+            quantize(linear_to_srgb(green)),
+            quantize(linear_to_srgb(blue)),
+            quantize(alpha),
+        ]
+    }
+}
+
+componentwise_vector_arithmetic_impl! {
+    Rgba { red: f64, green: f64, blue: f64, alpha: f64 }
+}
+
+#[inline]
+fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[inline]
+fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
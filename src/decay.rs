@@ -0,0 +1,153 @@
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The velocity below which [`Decay::duration`] considers the motion stopped,
+/// in units per second.
+const DEFAULT_VELOCITY_THRESHOLD: f64 = 0.5;
+
+/// An exponential deceleration, like that of a flung `UIScrollView`.
+///
+/// The velocity is multiplied by the deceleration rate every millisecond, so
+/// the motion slows down continuously and comes to rest at its
+/// [`destination`](Self::destination).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decay {
+    /// The fraction of the velocity kept after each millisecond, between 0
+    /// and 1 exclusive.
+    pub deceleration_rate: f64,
+}
+
+/// The moment a [`Decay`] reaches a boundary, at which a spring can take over
+/// the motion, as returned by [`Decay::into_spring_handoff`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecayHandoff {
+    /// The time at which the boundary is reached.
+    pub time: f64,
+    /// The velocity at that time, which is the initial velocity for the
+    /// spring.
+    pub velocity: f64,
+}
+
+impl Default for Decay {
+    /// Returns the [`NORMAL`](Self::NORMAL) deceleration.
+    #[inline]
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+impl Decay {
+    /// The deceleration rate of `UIScrollView.DecelerationRate.normal`.
+    pub const NORMAL: Self = Self::new(0.998);
+    /// The deceleration rate of `UIScrollView.DecelerationRate.fast`.
+    pub const FAST: Self = Self::new(0.99);
+
+    #[inline]
+    pub const fn new(deceleration_rate: f64) -> Self {
+        Self { deceleration_rate }
+    }
+
+    /// The rate at which the velocity decays, per second. This is negative.
+    #[inline]
+    fn decay_rate(&self) -> f64 {
+        1000.0 * self.deceleration_rate.ln()
+    }
+
+    /// Calculates the distance travelled at a given time given an initial
+    /// velocity.
+    pub fn value<V>(&self, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        let decay_rate = self.decay_rate();
+        initial_velocity.scaled_by(((decay_rate * time).exp() - 1.0) / decay_rate)
+    }
+
+    /// Calculates the velocity at a given time given an initial velocity.
+    pub fn velocity<V>(&self, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        initial_velocity.scaled_by((self.decay_rate() * time).exp())
+    }
+
+    /// The distance travelled by the time the motion comes to rest.
+    pub fn destination<V>(&self, initial_velocity: V) -> V
+    where
+        V: VectorArithmetic,
+    {
+        initial_velocity.scaled_by(-1.0 / self.decay_rate())
+    }
+
+    /// The time it takes for the speed to fall below half a unit per second,
+    /// approximately when the motion stops being visible for values measured
+    /// in points.
+    pub fn duration<V>(&self, initial_velocity: V) -> f64
+    where
+        V: VectorArithmetic,
+    {
+        self.duration_with_threshold(initial_velocity, DEFAULT_VELOCITY_THRESHOLD)
+    }
+
+    /// The time it takes for the speed to fall below `threshold`, in units
+    /// per second.
+    pub fn duration_with_threshold<V>(&self, initial_velocity: V, threshold: f64) -> f64
+    where
+        V: VectorArithmetic,
+    {
+        let speed = initial_velocity.magnitude();
+        if speed <= threshold {
+            return 0.0;
+        }
+        (threshold / speed).ln() / self.decay_rate()
+    }
+
+    /// Returns the moment the motion reaches `boundary`, measured as a
+    /// distance from the start, so that a spring can take over and settle
+    /// the motion at the boundary.
+    ///
+    /// Returns `None` if the motion comes to rest before the boundary, or
+    /// moves away from it.
+    pub fn into_spring_handoff(
+        &self,
+        initial_velocity: f64,
+        boundary: f64,
+    ) -> Option<DecayHandoff> {
+        if boundary == 0.0 {
+            return Some(DecayHandoff {
+                time: 0.0,
+                velocity: initial_velocity,
+            });
+        }
+        // The fraction of the initial velocity left at the boundary.
+        let remaining = 1.0 + boundary * self.decay_rate() / initial_velocity;
+        if !(remaining > 0.0 && remaining <= 1.0) {
+            return None;
+        }
+        Some(DecayHandoff {
+            time: remaining.ln() / self.decay_rate(),
+            velocity: initial_velocity * remaining,
+        })
+    }
+}
+
+/// Predicts where a value flung at `velocity` comes to rest under a
+/// [`Decay`] with the given deceleration rate.
+///
+/// This is the standard computation for choosing a snap target, such as the
+/// nearest page, before driving a spring towards it. Pass
+/// [`Decay::NORMAL`]'s rate for the feel of a scroll view.
+#[inline]
+pub fn project(value: f64, velocity: f64, deceleration_rate: f64) -> f64 {
+    project_vector(value, velocity, deceleration_rate)
+}
+
+/// Predicts where a vector value flung at `velocity` comes to rest, like
+/// [`project`].
+pub fn project_vector<V>(value: V, velocity: V, deceleration_rate: f64) -> V
+where
+    V: VectorArithmetic,
+{
+    value + Decay::new(deceleration_rate).destination(velocity)
+}
@@ -0,0 +1,37 @@
+use std::sync::{PoisonError, RwLock};
+
+use crate::spring::Spring;
+
+static DEFAULT_SPRING: RwLock<Option<Spring>> = RwLock::new(None);
+
+/// Held by tests that change the default spring, which is shared by every
+/// test in the process.
+#[cfg(test)]
+pub(crate) static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Sets the spring returned by `Spring::default()` for the whole process.
+///
+/// This lets an app change its motion feel in one place, for example to slow
+/// down or soften animations when reduce motion is enabled. Springs that were
+/// already created are not affected.
+pub fn set_default_spring(spring: Spring) {
+    *DEFAULT_SPRING
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(spring);
+}
+
+/// Restores the default spring to [`Spring::SMOOTH`].
+pub fn reset_default_spring() {
+    *DEFAULT_SPRING
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Returns the spring set with [`set_default_spring`], or [`Spring::SMOOTH`]
+/// if none was set.
+pub fn default_spring() -> Spring {
+    DEFAULT_SPRING
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .unwrap_or(Spring::SMOOTH)
+}
@@ -0,0 +1,101 @@
+use crate::error::SpringError;
+use crate::spring::Spring;
+
+/// The parameters a [`Spring`] was created from.
+///
+/// A `Spring` stores only its raw parameters, so reading the duration and
+/// bounce back from it is subject to rounding. A descriptor keeps the
+/// original parameterization, which makes it suitable for editing and for
+/// serialization round trips. Convert it with [`to_spring`](Self::to_spring)
+/// or `Spring::from` when the spring is needed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpringDescriptor {
+    /// See [`Spring::with_duration_bounce`].
+    DurationBounce { duration: f64, bounce: f64 },
+    /// See [`Spring::with_mass_stiffness_damping`].
+    MassStiffnessDamping {
+        mass: f64,
+        stiffness: f64,
+        damping: f64,
+        allow_over_damping: bool,
+    },
+    /// See [`Spring::with_response_damping_ratio`].
+    ResponseDampingRatio { response: f64, damping_ratio: f64 },
+    /// See [`Spring::new`].
+    Raw(Spring),
+}
+
+impl SpringDescriptor {
+    /// Creates the spring described by these parameters.
+    pub const fn to_spring(&self) -> Spring {
+        match *self {
+            Self::DurationBounce { duration, bounce } => {
+                Spring::with_duration_bounce(duration, bounce)
+            }
+            Self::MassStiffnessDamping {
+                mass,
+                stiffness,
+                damping,
+                allow_over_damping,
+            } => Spring::with_mass_stiffness_damping(mass, stiffness, damping, allow_over_damping),
+            Self::ResponseDampingRatio {
+                response,
+                damping_ratio,
+            } => Spring::with_response_damping_ratio(response, damping_ratio),
+            Self::Raw(spring) => spring,
+        }
+    }
+
+    /// Creates the spring described by these parameters, validating them with
+    /// the corresponding `try_*` constructor.
+    pub fn try_to_spring(&self) -> Result<Spring, SpringError> {
+        match *self {
+            Self::DurationBounce { duration, bounce } => {
+                Spring::try_with_duration_bounce(duration, bounce)
+            }
+            Self::MassStiffnessDamping {
+                mass,
+                stiffness,
+                damping,
+                allow_over_damping,
+            } => Spring::try_with_mass_stiffness_damping(
+                mass,
+                stiffness,
+                damping,
+                allow_over_damping,
+            ),
+            Self::ResponseDampingRatio {
+                response,
+                damping_ratio,
+            } => Spring::try_with_response_damping_ratio(response, damping_ratio),
+            Self::Raw(spring) => {
+                Spring::try_new(spring.angular_frequency, spring.decay_constant, spring.mass)
+            }
+        }
+    }
+}
+
+impl Default for SpringDescriptor {
+    /// Describes the [`smooth`](Spring::smooth) spring.
+    #[inline]
+    fn default() -> Self {
+        Self::DurationBounce {
+            duration: 0.5,
+            bounce: 0.0,
+        }
+    }
+}
+
+impl From<SpringDescriptor> for Spring {
+    #[inline]
+    fn from(descriptor: SpringDescriptor) -> Self {
+        descriptor.to_spring()
+    }
+}
+
+impl From<Spring> for SpringDescriptor {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        Self::Raw(spring)
+    }
+}
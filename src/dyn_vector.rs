@@ -0,0 +1,162 @@
+use std::ops::{Deref, DerefMut};
+
+/// Implements the arithmetic traits for a newtype over a list of `f64`
+/// components, zero-padding the shorter operand when lengths differ.
+macro_rules! padded_vector_arithmetic_impl {
+    (
+        [$($generics:tt)*] $t:ty,
+        zero: $zero:expr,
+        resize: |$components:ident, $len:ident| $resize:expr
+    ) => {
+        impl<$($generics)*> ::std::ops::AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                if self.0.len() < rhs.0.len() {
+                    let $components = &mut self.0;
+                    let $len = rhs.0.len();
+                    $resize;
+                }
+                for (lhs, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *lhs += *rhs;
+                }
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                if self.0.len() < rhs.0.len() {
+                    let $components = &mut self.0;
+                    let $len = rhs.0.len();
+                    $resize;
+                }
+                for (lhs, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *lhs -= *rhs;
+                }
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Add for $t {
+            type Output = Self;
+
+            #[inline]
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Sub for $t {
+            type Output = Self;
+
+            #[inline]
+            fn sub(mut self, rhs: Self) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl<$($generics)*> $crate::AdditiveArithmetic for $t {
+            const ZERO: Self = $zero;
+        }
+
+        impl<$($generics)*> $crate::VectorArithmetic for $t {
+            #[inline]
+            fn magnitude_squared(&self) -> f64 {
+                self.0.iter().map(|component| component * component).sum()
+            }
+
+            #[inline]
+            fn scale_by(&mut self, scalar: f64) {
+                for component in self.0.iter_mut() {
+                    *component *= scalar;
+                }
+            }
+
+            #[inline]
+            fn dot(&self, other: &Self) -> f64 {
+                // Missing components are zero, so they do not contribute.
+                self.0.iter().zip(other.0.iter()).map(|(lhs, rhs)| lhs * rhs).sum()
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "heapless", feature = "kurbo", feature = "smallvec"))]
+pub(crate) use padded_vector_arithmetic_impl;
+
+/// A runtime-sized vector of components that can be animated.
+///
+/// Vectors of different lengths can be combined: the shorter operand is
+/// treated as if it were padded with zeros, and the result has the length of
+/// the longer one. This makes [`AdditiveArithmetic::ZERO`], the empty vector,
+/// an identity for vectors of any length, so the spring APIs work without
+/// knowing the dimension up front.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynVector(pub Vec<f64>);
+
+impl DynVector {
+    /// Creates a vector from the given components.
+    #[inline]
+    pub const fn new(components: Vec<f64>) -> Self {
+        Self(components)
+    }
+
+    /// Creates a vector of `len` zero components.
+    #[inline]
+    pub fn zeros(len: usize) -> Self {
+        Self(vec![0.0; len])
+    }
+
+    /// Consumes the vector, returning its components.
+    #[inline]
+    pub fn into_inner(self) -> Vec<f64> {
+        self.0
+    }
+}
+
+impl From<Vec<f64>> for DynVector {
+    #[inline]
+    fn from(components: Vec<f64>) -> Self {
+        Self(components)
+    }
+}
+
+impl From<&[f64]> for DynVector {
+    #[inline]
+    fn from(components: &[f64]) -> Self {
+        Self(components.to_vec())
+    }
+}
+
+impl From<DynVector> for Vec<f64> {
+    #[inline]
+    fn from(vector: DynVector) -> Self {
+        vector.0
+    }
+}
+
+impl FromIterator<f64> for DynVector {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Deref for DynVector {
+    type Target = [f64];
+
+    #[inline]
+    fn deref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl DerefMut for DynVector {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
+padded_vector_arithmetic_impl! {
+    [] DynVector, zero: Self(Vec::new()), resize: |components, len| components.resize(len, 0.0)
+}
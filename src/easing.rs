@@ -0,0 +1,33 @@
+use crate::spring::Spring;
+
+impl Spring {
+    /// Returns an easing function that maps normalized progress through the
+    /// spring's settling duration to the progress of its value, for tweening
+    /// systems that consume easing functions.
+    ///
+    /// The spring starts at rest, its settling duration is computed with
+    /// `epsilon`, and a progress of 0 maps to 0 and 1 maps to exactly 1.
+    /// Outputs in between may exceed 1 when the spring overshoots. Progress
+    /// outside `0..=1` is clamped.
+    ///
+    /// Springs without damping never settle, so their easing spans their
+    /// [`duration`](Self::duration) instead.
+    pub fn as_easing(&self, epsilon: f64) -> impl Fn(f64) -> f64 + use<> {
+        let spring = *self;
+        let settling_duration = spring.settling_duration_with_velocity(1.0, 0.0, epsilon);
+        let duration = if settling_duration.is_finite() {
+            settling_duration
+        } else {
+            spring.duration()
+        };
+        move |progress| {
+            if progress >= 1.0 {
+                1.0
+            } else if progress > 0.0 {
+                spring.value(1.0, 0.0, progress * duration)
+            } else {
+                0.0
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The mechanical energy of a spring in motion, computed by
+/// [`Spring::energy`].
+///
+/// Energies are in units of mass times the squared units of the vector type
+/// per second squared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringEnergy {
+    /// The energy of the motion of the mass, `m v² / 2`.
+    pub kinetic: f64,
+    /// The energy stored in the stretched spring, `k x² / 2`, where `x` is
+    /// the distance to the target.
+    pub potential: f64,
+    /// The sum of the kinetic and potential energy. Damping removes this
+    /// energy over time, so it only ever decreases.
+    pub total: f64,
+}
+
+impl Spring {
+    /// Returns the energy of the spring at `position` moving at `velocity`
+    /// towards `target`.
+    pub fn energy<V>(&self, position: &V, velocity: &V, target: &V) -> SpringEnergy
+    where
+        V: VectorArithmetic,
+    {
+        let displacement = target.clone() - position.clone();
+        let kinetic = 0.5 * self.mass * velocity.magnitude_squared();
+        let potential = 0.5 * self.stiffness() * displacement.magnitude_squared();
+        SpringEnergy {
+            kinetic,
+            potential,
+            total: kinetic + potential,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+/// An error returned by the validating `try_*` constructors of
+/// [`Spring`](crate::Spring), [`BoundedSpring`](crate::BoundedSpring) and
+/// [`Quantizer`](crate::Quantizer), and by
+/// [`SpringBuilder::try_build`](crate::SpringBuilder::try_build).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpringError {
+    /// The duration, response, or settling duration is zero or negative.
+    ZeroDuration,
+    /// The mass is zero or negative.
+    NonPositiveMass,
+    /// The stiffness is zero or negative.
+    NonPositiveStiffness,
+    /// The damping coefficient is negative.
+    NegativeDamping,
+    /// The settling threshold is zero or negative.
+    NonPositiveEpsilon,
+    /// A parameter is NaN or infinite.
+    NonFinite,
+    /// The bounce does not satisfy `-1.0 < bounce <= 1.0`, or the damping
+    /// ratio is outside the range accepted by the constructor.
+    BounceOutOfRange,
+    /// Parameters from more than one parameterization were given.
+    ConflictingParameters,
+    /// The lower bound is greater than the upper bound, or a bound is NaN.
+    InvalidBounds,
+    /// The quantum of a quantizer is zero or negative.
+    NonPositiveQuantum,
+}
+
+impl fmt::Display for SpringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ZeroDuration => "spring duration must be positive",
+            Self::NonPositiveMass => "spring mass must be positive",
+            Self::NonPositiveStiffness => "spring stiffness must be positive",
+            Self::NegativeDamping => "spring damping must not be negative",
+            Self::NonPositiveEpsilon => "spring settling threshold must be positive",
+            Self::NonFinite => "spring parameters must be finite",
+            Self::BounceOutOfRange => "spring bounce is out of range",
+            Self::ConflictingParameters => "spring parameterizations cannot be mixed",
+            Self::InvalidBounds => "spring lower bound must not exceed the upper bound",
+            Self::NonPositiveQuantum => "quantizer quantum must be positive",
+        })
+    }
+}
+
+impl std::error::Error for SpringError {}
+
+impl SpringError {
+    /// Fails with [`NonFinite`](Self::NonFinite) unless every value is finite.
+    #[inline]
+    pub(crate) fn ensure_finite(values: &[f64]) -> Result<(), Self> {
+        if values.iter().all(|value| value.is_finite()) {
+            Ok(())
+        } else {
+            Err(Self::NonFinite)
+        }
+    }
+
+    /// Fails with [`ZeroDuration`](Self::ZeroDuration) unless `duration` is
+    /// positive.
+    #[inline]
+    pub(crate) fn ensure_duration(duration: f64) -> Result<(), Self> {
+        if duration > 0.0 {
+            Ok(())
+        } else {
+            Err(Self::ZeroDuration)
+        }
+    }
+
+    /// Fails with [`BounceOutOfRange`](Self::BounceOutOfRange) unless
+    /// `-1.0 < bounce <= 1.0`.
+    #[inline]
+    pub(crate) fn ensure_bounce(bounce: f64) -> Result<(), Self> {
+        if bounce > -1.0 && bounce <= 1.0 {
+            Ok(())
+        } else {
+            Err(Self::BounceOutOfRange)
+        }
+    }
+}
+
+/// An error returned when parsing a [`Spring`](crate::Spring) from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseSpringError {
+    /// The string is not a well-formed spring descriptor.
+    InvalidSyntax,
+    /// The preset name or parameter key is not recognized.
+    UnknownName,
+    /// An argument is not a number.
+    InvalidNumber,
+    /// The number of arguments is wrong.
+    WrongArgumentCount,
+    /// The arguments do not describe a valid spring.
+    InvalidSpring(SpringError),
+}
+
+impl fmt::Display for ParseSpringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => f.write_str("invalid spring descriptor"),
+            Self::UnknownName => f.write_str("unknown spring preset or parameter"),
+            Self::InvalidNumber => f.write_str("spring argument is not a number"),
+            Self::WrongArgumentCount => f.write_str("wrong number of spring arguments"),
+            Self::InvalidSpring(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseSpringError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidSpring(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SpringError> for ParseSpringError {
+    #[inline]
+    fn from(error: SpringError) -> Self {
+        Self::InvalidSpring(error)
+    }
+}
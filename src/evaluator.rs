@@ -0,0 +1,220 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The number of samples after which [`SpringEvaluator::sample_into`]
+/// recomputes its weights exactly.
+const RESEED_INTERVAL: usize = 256;
+
+/// Evaluates the motion of a spring towards a fixed target from a fixed
+/// initial velocity, created with [`Spring::evaluator`].
+///
+/// The remaining distance to the target is a combination of two fixed vectors
+/// weighted by scalar functions of time. The evaluator computes those vectors
+/// once, so each evaluation only combines them, which is considerably cheaper
+/// than [`Spring::value`] when sampling the same motion many times.
+#[derive(Debug, Clone)]
+pub struct SpringEvaluator<V> {
+    spring: Spring,
+    target: V,
+    pub(crate) first: V,
+    pub(crate) second: V,
+}
+
+impl Spring {
+    /// Returns an evaluator for the motion of the spring given a target amount
+    /// of change and an initial velocity.
+    pub fn evaluator<V>(&self, target: V, initial_velocity: V) -> SpringEvaluator<V>
+    where
+        V: VectorArithmetic,
+    {
+        SpringEvaluator::new(*self, target, initial_velocity)
+    }
+
+    /// Returns `count` values of the spring at evenly spaced times given a
+    /// target amount of change, starting at time 0 and advancing by
+    /// `delta_time` per sample.
+    ///
+    /// See [`SpringEvaluator::sample_into`] for how the samples are computed.
+    pub fn samples<V>(
+        &self,
+        target: V,
+        initial_velocity: V,
+        delta_time: f64,
+        count: usize,
+    ) -> Vec<V>
+    where
+        V: VectorArithmetic,
+    {
+        let mut samples = vec![V::ZERO; count];
+        self.sample_into(target, initial_velocity, delta_time, &mut samples);
+        samples
+    }
+
+    /// Fills `output` with values of the spring at evenly spaced times given a
+    /// target amount of change, starting at time 0 and advancing by
+    /// `delta_time` per element.
+    ///
+    /// This is the allocation-free variant of [`samples`](Self::samples).
+    pub fn sample_into<V>(&self, target: V, initial_velocity: V, delta_time: f64, output: &mut [V])
+    where
+        V: VectorArithmetic,
+    {
+        self.evaluator(target, initial_velocity)
+            .sample_into(output, delta_time);
+    }
+}
+
+impl<V> SpringEvaluator<V>
+where
+    V: VectorArithmetic,
+{
+    /// Creates an evaluator for the motion of `spring` given a target amount
+    /// of change and an initial velocity.
+    pub fn new(spring: Spring, target: V, initial_velocity: V) -> Self {
+        let decay = spring.decay_constant;
+        let frequency = spring.angular_frequency;
+        let (first, second) = if frequency > 0.0 {
+            let decayed = target.clone().scaled_by(decay) - initial_velocity;
+            (target.clone(), decayed.scaled_by(1.0 / frequency))
+        } else if frequency < 0.0 {
+            let (slow_rate, fast_rate) = (-decay - frequency, frequency - decay);
+            // The coefficients sum to the target, and their rates of change
+            // sum to the negated initial velocity.
+            let slow = (initial_velocity + target.clone().scaled_by(fast_rate))
+                .scaled_by(-1.0 / (slow_rate - fast_rate));
+            let fast = target.clone() - slow.clone();
+            (slow, fast)
+        } else {
+            let decayed = target.clone().scaled_by(decay) - initial_velocity;
+            (target.clone(), decayed)
+        };
+        Self {
+            spring,
+            target,
+            first,
+            second,
+        }
+    }
+
+    /// The spring being evaluated.
+    #[inline]
+    pub fn spring(&self) -> &Spring {
+        &self.spring
+    }
+
+    /// The target amount of change.
+    #[inline]
+    pub fn target(&self) -> &V {
+        &self.target
+    }
+
+    /// Calculates the value of the spring at a given time.
+    ///
+    /// This matches [`Spring::value`] up to rounding, including at negative times.
+    pub fn value_at(&self, time: f64) -> V {
+        let [first, second, _, _] = self.basis(time);
+        self.target.clone() - self.combine(first, second)
+    }
+
+    /// Calculates the velocity of the spring at a given time.
+    ///
+    /// This matches [`Spring::velocity`] up to rounding, including at negative times.
+    pub fn velocity_at(&self, time: f64) -> V {
+        let [_, _, first, second] = self.basis(time);
+        V::ZERO - self.combine(first, second)
+    }
+
+    /// Fills `output` with the values of the spring at evenly spaced times,
+    /// starting at time 0 and advancing by `delta_time` per element.
+    ///
+    /// Rather than evaluating the exponential and trigonometric functions for
+    /// every sample, each sample is advanced from the previous one by a fixed
+    /// factor, using the angle-addition identities for oscillating springs.
+    /// The weights are recomputed exactly every few hundred samples so that
+    /// rounding does not accumulate.
+    pub fn sample_into(&self, output: &mut [V], delta_time: f64) {
+        self.sample_range_into(output, 0, delta_time);
+    }
+
+    /// Fills `output` with the samples starting at `first_index`, as if it
+    /// were that part of the output of [`sample_into`](Self::sample_into).
+    pub(crate) fn sample_range_into(&self, output: &mut [V], first_index: usize, delta_time: f64) {
+        let step = self.step_matrix(delta_time);
+        let mut weights = [0.0; 2];
+        for (offset, sample) in output.iter_mut().enumerate() {
+            let index = first_index + offset;
+            weights = if offset == 0 || index.is_multiple_of(RESEED_INTERVAL) {
+                let [first, second, _, _] = self.basis(index as f64 * delta_time);
+                [first, second]
+            } else {
+                let [first, second] = weights;
+                [
+                    step[0][0] * first + step[0][1] * second,
+                    step[1][0] * first + step[1][1] * second,
+                ]
+            };
+            *sample = self.target.clone() - self.combine(weights[0], weights[1]);
+        }
+    }
+
+    /// The matrix that advances the weights of the coefficient vectors by
+    /// `delta_time`.
+    fn step_matrix(&self, delta_time: f64) -> [[f64; 2]; 2] {
+        let decay = self.spring.decay_constant;
+        let frequency = self.spring.angular_frequency;
+        if frequency > 0.0 {
+            // Rotate by the angle of one step and decay by its envelope.
+            let envelope = (-decay * delta_time).exp();
+            let (sin, cos) = (frequency * delta_time).sin_cos();
+            [
+                [envelope * cos, -envelope * sin],
+                [envelope * sin, envelope * cos],
+            ]
+        } else if frequency < 0.0 {
+            let (slow_rate, fast_rate) = (-decay - frequency, frequency - decay);
+            [
+                [(slow_rate * delta_time).exp(), 0.0],
+                [0.0, (fast_rate * delta_time).exp()],
+            ]
+        } else {
+            // `e^(-d(t + Δt)) (t + Δt)` advances from both weights.
+            let envelope = (-decay * delta_time).exp();
+            [[envelope, 0.0], [envelope * delta_time, envelope]]
+        }
+    }
+
+    #[inline]
+    fn combine(&self, first: f64, second: f64) -> V {
+        self.first.clone().scaled_by(first) + self.second.clone().scaled_by(second)
+    }
+
+    /// The weights of the two coefficient vectors in the remaining distance,
+    /// followed by their derivatives.
+    fn basis(&self, time: f64) -> [f64; 4] {
+        let decay = self.spring.decay_constant;
+        let frequency = self.spring.angular_frequency;
+        if frequency > 0.0 {
+            let envelope = (-decay * time).exp();
+            let (sin, cos) = (frequency * time).sin_cos();
+            [
+                envelope * cos,
+                envelope * sin,
+                -envelope * (decay * cos + frequency * sin),
+                envelope * (frequency * cos - decay * sin),
+            ]
+        } else if frequency < 0.0 {
+            let (slow_rate, fast_rate) = (-decay - frequency, frequency - decay);
+            let slow = (slow_rate * time).exp();
+            let fast = (fast_rate * time).exp();
+            [slow, fast, slow_rate * slow, fast_rate * fast]
+        } else {
+            let envelope = (-decay * time).exp();
+            [
+                envelope,
+                envelope * time,
+                -decay * envelope,
+                envelope * (1.0 - decay * time),
+            ]
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+
+use crate::spring::Spring;
+
+/// Samples of a spring's motion over a range of time, ready to be written out
+/// for plotting, created with [`Spring::export`].
+///
+/// Each row holds the time, value and velocity of the spring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveExport {
+    pub spring: Spring,
+    /// The target amount of change.
+    pub target: f64,
+    pub initial_velocity: f64,
+    /// The range of time covered by the samples.
+    pub range: Range<f64>,
+    /// The number of evenly spaced samples, which include both ends of the
+    /// range.
+    pub sample_count: usize,
+}
+
+impl Spring {
+    /// Returns `sample_count` evenly spaced samples of the motion of the
+    /// spring over `range`, given a target amount of change.
+    #[inline]
+    pub fn export(
+        &self,
+        target: f64,
+        initial_velocity: f64,
+        range: Range<f64>,
+        sample_count: usize,
+    ) -> CurveExport {
+        CurveExport {
+            spring: *self,
+            target,
+            initial_velocity,
+            range,
+            sample_count,
+        }
+    }
+}
+
+impl CurveExport {
+    /// Returns an iterator over the `(time, value, velocity)` rows.
+    pub fn rows(&self) -> impl Iterator<Item = (f64, f64, f64)> + '_ {
+        let span = self.range.end - self.range.start;
+        let intervals = self.sample_count.saturating_sub(1).max(1) as f64;
+        (0..self.sample_count).map(move |index| {
+            let time = self.range.start + span * (index as f64 / intervals);
+            (
+                time,
+                self.spring.value(self.target, self.initial_velocity, time),
+                self.spring
+                    .velocity(self.target, self.initial_velocity, time),
+            )
+        })
+    }
+
+    /// Writes the rows as CSV with a `time,value,velocity` header.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "time,value,velocity")?;
+        for (time, value, velocity) in self.rows() {
+            writeln!(writer, "{time},{value},{velocity}")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the rows as a JSON array of objects with `time`, `value` and
+    /// `velocity` fields.
+    ///
+    /// Non-finite numbers, which JSON cannot represent, are written as `null`.
+    pub fn to_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "[")?;
+        for (index, (time, value, velocity)) in self.rows().enumerate() {
+            if index > 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{{\"time\":{},", JsonNumber(time))?;
+            write!(writer, "\"value\":{},", JsonNumber(value))?;
+            write!(writer, "\"velocity\":{}}}", JsonNumber(velocity))?;
+        }
+        writeln!(writer, "]")
+    }
+}
+
+/// Formats a number as JSON, which has no representation for non-finite
+/// numbers.
+struct JsonNumber(f64);
+
+impl fmt::Display for JsonNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_finite() {
+            write!(f, "{}", self.0)
+        } else {
+            f.write_str("null")
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A spring acted on by a constant external force, such as gravity pulling on
+/// a hanging element.
+///
+/// A constant force shifts the rest position of the spring away from its
+/// target by the [`rest_offset`](Self::rest_offset), and otherwise leaves the
+/// motion unchanged, so the spring settles where the two forces balance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalForceSpring<V> {
+    pub spring: Spring,
+    /// The force, in units of mass times the vector type per second squared.
+    pub force: V,
+}
+
+impl Spring {
+    /// Returns a version of this spring acted on by a constant `force`.
+    #[inline]
+    pub const fn with_external_force<V>(self, force: V) -> ExternalForceSpring<V> {
+        ExternalForceSpring::new(self, force)
+    }
+}
+
+impl<V> ExternalForceSpring<V> {
+    #[inline]
+    pub const fn new(spring: Spring, force: V) -> Self {
+        Self { spring, force }
+    }
+}
+
+impl<V> ExternalForceSpring<V>
+where
+    V: VectorArithmetic,
+{
+    /// The displacement of the rest position from the target, which is the
+    /// force divided by the stiffness.
+    #[inline]
+    pub fn rest_offset(&self) -> V {
+        self.force.clone().scaled_by(1.0 / self.spring.stiffness())
+    }
+
+    /// Calculates the value of the spring at a given time given a target
+    /// amount of change, which settles at the target plus the rest offset.
+    pub fn value(&self, target: V, initial_velocity: V, time: f64) -> V {
+        self.spring
+            .value(target + self.rest_offset(), initial_velocity, time)
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target
+    /// amount of change.
+    pub fn velocity(&self, target: V, initial_velocity: V, time: f64) -> V {
+        self.spring
+            .velocity(target + self.rest_offset(), initial_velocity, time)
+    }
+
+    /// Updates the current value and velocity of a spring pulled towards
+    /// `target` and by the external force.
+    pub fn update(&self, value: &mut V, velocity: &mut V, target: V, delta_time: f64) {
+        self.spring
+            .update(value, velocity, target + self.rest_offset(), delta_time);
+    }
+
+    /// Calculates the total force upon the spring, including the external
+    /// force, given a current position, target, and velocity.
+    pub fn force(&self, target: V, position: V, velocity: V) -> V {
+        self.spring.force(target, position, velocity) + self.force.clone()
+    }
+}
@@ -0,0 +1,218 @@
+use std::f64::consts::PI;
+
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl Spring {
+    /// Returns the times at which the motion reaches a local peak, with the
+    /// value of the spring at each, in increasing order of time.
+    ///
+    /// A peak is a time after the start at which the velocity along the
+    /// direction of travel becomes zero. Underdamped springs peak once per
+    /// half oscillation forever, so the iterator never ends for them; bound it
+    /// with [`take`](Iterator::take) or by the
+    /// [`settling_duration`](Self::settling_duration). Critically damped and
+    /// overdamped springs peak at most once.
+    ///
+    /// For vector types, the motion is measured along the direction of
+    /// `target`, or of `initial_velocity` if the target is zero.
+    pub fn extrema<V>(
+        &self,
+        target: V,
+        initial_velocity: V,
+    ) -> impl Iterator<Item = (f64, V)> + use<V>
+    where
+        V: VectorArithmetic,
+    {
+        let times = match self.displacement_coefficients(&target, &initial_velocity) {
+            Some(Displacement::Oscillating { cos, sin }) => {
+                // The derivative of `e^(-dt) (A cos ωt + B sin ωt)`.
+                let (decay, frequency) = (self.decay_constant, self.angular_frequency);
+                EventTimes::periodic(
+                    first_root(
+                        frequency * sin - decay * cos,
+                        -decay * sin - frequency * cos,
+                    ) / frequency,
+                    PI / frequency,
+                )
+            }
+            Some(Displacement::Critical { constant, linear }) => {
+                // The derivative is `e^(-dt) ((K - dτ) - dK t)`.
+                let decay = self.decay_constant;
+                EventTimes::single((linear - decay * constant) / (decay * linear))
+            }
+            Some(Displacement::Overdamped {
+                slow,
+                fast,
+                slow_rate,
+                fast_rate,
+            }) => EventTimes::single(
+                (-(fast * fast_rate) / (slow * slow_rate)).ln() / (slow_rate - fast_rate),
+            ),
+            None => EventTimes::NONE,
+        };
+        self.values_at(times, target, initial_velocity)
+    }
+
+    /// Returns the times at which the motion crosses the target, with the
+    /// value of the spring at each, in increasing order of time.
+    ///
+    /// Like [`extrema`](Self::extrema), the iterator never ends for
+    /// underdamped springs, and yields at most one crossing for critically
+    /// damped and overdamped springs. A spring that starts at the target is
+    /// not considered to cross it at the start.
+    pub fn zero_crossings<V>(
+        &self,
+        target: V,
+        initial_velocity: V,
+    ) -> impl Iterator<Item = (f64, V)> + use<V>
+    where
+        V: VectorArithmetic,
+    {
+        let times = match self.displacement_coefficients(&target, &initial_velocity) {
+            Some(Displacement::Oscillating { cos, sin }) => {
+                let frequency = self.angular_frequency;
+                EventTimes::periodic(first_root(cos, sin) / frequency, PI / frequency)
+            }
+            Some(Displacement::Critical { constant, linear }) => {
+                EventTimes::single(-constant / linear)
+            }
+            Some(Displacement::Overdamped {
+                slow,
+                fast,
+                slow_rate,
+                fast_rate,
+            }) => EventTimes::single((-fast / slow).ln() / (slow_rate - fast_rate)),
+            None => EventTimes::NONE,
+        };
+        self.values_at(times, target, initial_velocity)
+    }
+
+    /// Decomposes the remaining distance to the target along the direction of
+    /// travel, or returns `None` if the spring is at rest at the target.
+    fn displacement_coefficients<V>(&self, target: &V, initial_velocity: &V) -> Option<Displacement>
+    where
+        V: VectorArithmetic,
+    {
+        let direction = if target.magnitude_squared() > 0.0 {
+            target
+        } else {
+            initial_velocity
+        };
+        let length_squared = direction.magnitude_squared();
+        if length_squared == 0.0 {
+            return None;
+        }
+        let target = target.dot(direction) / length_squared;
+        let initial_velocity = initial_velocity.dot(direction) / length_squared;
+
+        let decay = self.decay_constant;
+        let frequency = self.angular_frequency;
+        let displacement = if frequency > 0.0 {
+            Displacement::Oscillating {
+                cos: target,
+                sin: (target * decay - initial_velocity) / frequency,
+            }
+        } else if frequency < 0.0 {
+            let slow_rate = -decay - frequency;
+            let fast_rate = frequency - decay;
+            // The coefficients satisfy `slow + fast = target` and
+            // `slow_rate * slow + fast_rate * fast = -initial_velocity`.
+            let slow = (-initial_velocity - fast_rate * target) / (slow_rate - fast_rate);
+            Displacement::Overdamped {
+                slow,
+                fast: target - slow,
+                slow_rate,
+                fast_rate,
+            }
+        } else {
+            Displacement::Critical {
+                constant: target,
+                linear: target * decay - initial_velocity,
+            }
+        };
+        Some(displacement)
+    }
+
+    fn values_at<V>(
+        &self,
+        times: EventTimes,
+        target: V,
+        initial_velocity: V,
+    ) -> impl Iterator<Item = (f64, V)> + use<V>
+    where
+        V: VectorArithmetic,
+    {
+        let spring = *self;
+        times.map(move |time| {
+            let value = spring.value(target.clone(), initial_velocity.clone(), time);
+            (time, value)
+        })
+    }
+}
+
+/// The remaining distance to the target as a function of time, in the scalar
+/// units of the direction of travel.
+enum Displacement {
+    /// `e^(-dt) (cos · cos ωt + sin · sin ωt)`.
+    Oscillating { cos: f64, sin: f64 },
+    /// `e^(-dt) (constant + linear · t)`.
+    Critical { constant: f64, linear: f64 },
+    /// `slow · e^(slow_rate · t) + fast · e^(fast_rate · t)`.
+    Overdamped {
+        slow: f64,
+        fast: f64,
+        slow_rate: f64,
+        fast_rate: f64,
+    },
+}
+
+/// The smallest angle in `(0, π]` at which `a cos θ + b sin θ` is zero.
+fn first_root(a: f64, b: f64) -> f64 {
+    let angle = (-a).atan2(b).rem_euclid(PI);
+    if angle > 0.0 { angle } else { PI }
+}
+
+/// Evenly spaced event times, or at most a single one.
+struct EventTimes {
+    first: f64,
+    spacing: f64,
+    periodic: bool,
+    index: u64,
+}
+
+impl EventTimes {
+    const NONE: Self = Self::single(f64::NAN);
+
+    const fn periodic(first: f64, spacing: f64) -> Self {
+        Self {
+            first,
+            spacing,
+            periodic: true,
+            index: 0,
+        }
+    }
+
+    /// A single event at `time`, dropped unless it lies after the start.
+    const fn single(time: f64) -> Self {
+        Self {
+            first: time,
+            spacing: 0.0,
+            periodic: false,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for EventTimes {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if !(self.first.is_finite() && self.first > 0.0) || (!self.periodic && self.index > 0) {
+            return None;
+        }
+        let time = self.first + self.spacing * self.index as f64;
+        self.index += 1;
+        Some(time)
+    }
+}
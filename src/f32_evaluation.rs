@@ -0,0 +1,134 @@
+use crate::spring::Spring;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl<const N: usize> Sealed for [f32; N] {}
+}
+
+/// A single-precision value that [`Spring`] can evaluate in double precision
+/// with [`value_f32`](Spring::value_f32), [`velocity_f32`](Spring::velocity_f32)
+/// and [`update_f32`](Spring::update_f32).
+///
+/// This trait is sealed and implemented for `f32` and `[f32; N]`.
+pub trait F32Vector: private::Sealed + Copy {
+    /// Combines two values component by component in `f64`, rounding each
+    /// result to `f32` once.
+    #[doc(hidden)]
+    fn map2(a: Self, b: Self, f: impl Fn(f64, f64) -> f64) -> Self;
+
+    /// Combines three values component by component in `f64`, rounding each
+    /// result to `f32` once.
+    #[doc(hidden)]
+    fn map3(a: Self, b: Self, c: Self, f: impl Fn(f64, f64, f64) -> f64) -> Self;
+}
+
+impl F32Vector for f32 {
+    #[inline]
+    fn map2(a: Self, b: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        f(a as f64, b as f64) as f32
+    }
+
+    #[inline]
+    fn map3(a: Self, b: Self, c: Self, f: impl Fn(f64, f64, f64) -> f64) -> Self {
+        f(a as f64, b as f64, c as f64) as f32
+    }
+}
+
+impl<const N: usize> F32Vector for [f32; N] {
+    #[inline]
+    fn map2(a: Self, b: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        std::array::from_fn(|i| f(a[i] as f64, b[i] as f64) as f32)
+    }
+
+    #[inline]
+    fn map3(a: Self, b: Self, c: Self, f: impl Fn(f64, f64, f64) -> f64) -> Self {
+        std::array::from_fn(|i| f(a[i] as f64, b[i] as f64, c[i] as f64) as f32)
+    }
+}
+
+impl Spring {
+    /// Calculates the value of the spring at a given time given a target
+    /// amount of change, for single-precision values.
+    ///
+    /// The motion is computed in `f64` and rounded to `f32` once, unlike
+    /// converting the spring to a [`Spring32`](crate::Spring32).
+    #[inline]
+    pub fn value_f32<V: F32Vector>(&self, target: V, initial_velocity: V, time: f64) -> V {
+        // The motion is linear in the target and the initial velocity.
+        let target_factor = self.value(1.0, 0.0, time);
+        let velocity_factor = self.value(0.0, 1.0, time);
+        V::map2(target, initial_velocity, |target, velocity| {
+            target * target_factor + velocity * velocity_factor
+        })
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target
+    /// amount of change, for single-precision values.
+    #[inline]
+    pub fn velocity_f32<V: F32Vector>(&self, target: V, initial_velocity: V, time: f64) -> V {
+        let target_factor = self.velocity(1.0, 0.0, time);
+        let velocity_factor = self.velocity(0.0, 1.0, time);
+        V::map2(target, initial_velocity, |target, velocity| {
+            target * target_factor + velocity * velocity_factor
+        })
+    }
+
+    /// Updates the current value and velocity of a spring, for
+    /// single-precision values.
+    ///
+    /// The step is computed in `f64`, but the state is stored as `f32`, so
+    /// the rounding of each call is carried into the next and drifts over
+    /// long animations. For those, use an
+    /// [`AnchoredSpring`](crate::AnchoredSpring) instead, which evaluates the
+    /// motion from where it started on every frame, so no frame inherits the
+    /// rounding of the last. An `AnchoredSpring<f64>` whose value is rounded
+    /// to `f32` for display keeps the whole state in `f64`.
+    pub fn update_f32<V: F32Vector>(
+        &self,
+        value: &mut V,
+        velocity: &mut V,
+        target: V,
+        delta_time: f64,
+    ) {
+        let value_target_factor = self.value(1.0, 0.0, delta_time);
+        let value_velocity_factor = self.value(0.0, 1.0, delta_time);
+        let velocity_target_factor = self.velocity(1.0, 0.0, delta_time);
+        let velocity_velocity_factor = self.velocity(0.0, 1.0, delta_time);
+
+        let (current_value, current_velocity) = (*value, *velocity);
+        *value = V::map3(
+            current_value,
+            current_velocity,
+            target,
+            |value, velocity, target| {
+                value + (target - value) * value_target_factor + velocity * value_velocity_factor
+            },
+        );
+        *velocity = V::map3(
+            current_value,
+            current_velocity,
+            target,
+            |value, velocity, target| {
+                (target - value) * velocity_target_factor + velocity * velocity_velocity_factor
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_each_component_in_double_precision() {
+        let spring = Spring::bouncy();
+        let value = spring.value_f32([1.0_f32, -2.0], [0.5, 3.0], 0.1);
+        let velocity = spring.velocity_f32([1.0_f32, -2.0], [0.5, 3.0], 0.1);
+        assert_eq!(value[0], spring.value(1.0, 0.5, 0.1) as f32);
+        assert_eq!(value[1], spring.value(-2.0, 3.0, 0.1) as f32);
+        assert_eq!(velocity[0], spring.velocity(1.0, 0.5, 0.1) as f32);
+        assert_eq!(velocity[1], spring.velocity(-2.0, 3.0, 0.1) as f32);
+    }
+}
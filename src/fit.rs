@@ -0,0 +1,150 @@
+use crate::spring::Spring;
+
+/// The result of fitting a spring to sampled motion with [`Spring::fit`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitResult {
+    /// The spring that best matches the samples.
+    pub spring: Spring,
+    /// The root-mean-square difference between the samples and the motion of
+    /// `spring`, in the normalized units of the samples.
+    pub rms_error: f64,
+}
+
+/// The range of durations considered by the initial search.
+const MIN_DURATION: f64 = 0.02;
+const MAX_DURATION: f64 = 20.0;
+/// The range of bounces considered by the fit. The lower bound keeps the
+/// spring away from the infinitely overdamped limit.
+const MIN_BOUNCE: f64 = -0.99;
+const MAX_BOUNCE: f64 = 1.0;
+
+const GRID_DURATIONS: usize = 48;
+const GRID_BOUNCES: usize = 40;
+const REFINEMENT_ITERATIONS: usize = 200;
+
+impl Spring {
+    /// Fits a spring to recorded motion by least squares over its duration and
+    /// bounce.
+    ///
+    /// Each sample is a `(time, progress)` pair, where progress is the
+    /// position normalized so that the motion starts at 0 with zero velocity
+    /// at time 0 and settles at 1. Samples may be given in any order.
+    ///
+    /// With no samples, the [`smooth`](Self::smooth) spring is returned with a
+    /// zero error.
+    pub fn fit(samples: &[(f64, f64)]) -> FitResult {
+        if samples.is_empty() {
+            return FitResult {
+                spring: Self::SMOOTH,
+                rms_error: 0.0,
+            };
+        }
+
+        // Search in the log of the duration, which varies over orders of
+        // magnitude, and in the bounce.
+        let cost = |point: [f64; 2]| squared_error(samples, spring_at(point));
+
+        let mut best = [MIN_DURATION.ln(), 0.0];
+        let mut best_cost = f64::INFINITY;
+        for i in 0..GRID_DURATIONS {
+            let log_duration = MIN_DURATION.ln()
+                + (MAX_DURATION / MIN_DURATION).ln() * i as f64 / (GRID_DURATIONS - 1) as f64;
+            for j in 0..GRID_BOUNCES {
+                let bounce =
+                    MIN_BOUNCE + (MAX_BOUNCE - MIN_BOUNCE) * j as f64 / (GRID_BOUNCES - 1) as f64;
+                let point = [log_duration, bounce];
+                let point_cost = cost(point);
+                if point_cost < best_cost {
+                    best = point;
+                    best_cost = point_cost;
+                }
+            }
+        }
+
+        let best = nelder_mead(cost, best, [0.1, 0.05]);
+        let spring = spring_at(best);
+        FitResult {
+            spring,
+            rms_error: (squared_error(samples, spring) / samples.len() as f64).sqrt(),
+        }
+    }
+}
+
+#[inline]
+fn spring_at([log_duration, bounce]: [f64; 2]) -> Spring {
+    Spring::with_duration_bounce(log_duration.exp(), bounce.clamp(MIN_BOUNCE, MAX_BOUNCE))
+}
+
+fn squared_error(samples: &[(f64, f64)], spring: Spring) -> f64 {
+    samples
+        .iter()
+        .map(|&(time, progress)| {
+            let error = spring.value(1.0, 0.0, time) - progress;
+            error * error
+        })
+        .sum::<f64>()
+}
+
+/// Minimizes `cost` over two dimensions with the Nelder–Mead simplex method,
+/// starting from `start` with the given initial step sizes.
+fn nelder_mead(cost: impl Fn([f64; 2]) -> f64, start: [f64; 2], steps: [f64; 2]) -> [f64; 2] {
+    let mut simplex = [
+        start,
+        [start[0] + steps[0], start[1]],
+        [start[0], start[1] + steps[1]],
+    ];
+    let mut costs = simplex.map(&cost);
+
+    let along = |from: [f64; 2], to: [f64; 2], factor: f64| {
+        [
+            from[0] + (to[0] - from[0]) * factor,
+            from[1] + (to[1] - from[1]) * factor,
+        ]
+    };
+
+    for _ in 0..REFINEMENT_ITERATIONS {
+        let mut order = [0, 1, 2];
+        order.sort_by(|&a, &b| costs[a].total_cmp(&costs[b]));
+        let [best, middle, worst] = order;
+        if (costs[worst] - costs[best]).abs() <= f64::EPSILON * costs[best].abs() {
+            break;
+        }
+
+        let centroid = along(simplex[best], simplex[middle], 0.5);
+        let reflected = along(simplex[worst], centroid, 2.0);
+        let reflected_cost = cost(reflected);
+
+        if reflected_cost < costs[best] {
+            let expanded = along(simplex[worst], centroid, 3.0);
+            let expanded_cost = cost(expanded);
+            if expanded_cost < reflected_cost {
+                simplex[worst] = expanded;
+                costs[worst] = expanded_cost;
+            } else {
+                simplex[worst] = reflected;
+                costs[worst] = reflected_cost;
+            }
+        } else if reflected_cost < costs[middle] {
+            simplex[worst] = reflected;
+            costs[worst] = reflected_cost;
+        } else {
+            let contracted = along(simplex[worst], centroid, 0.5);
+            let contracted_cost = cost(contracted);
+            if contracted_cost < costs[worst] {
+                simplex[worst] = contracted;
+                costs[worst] = contracted_cost;
+            } else {
+                // Shrink towards the best vertex.
+                for vertex in [middle, worst] {
+                    simplex[vertex] = along(simplex[best], simplex[vertex], 0.5);
+                    costs[vertex] = cost(simplex[vertex]);
+                }
+            }
+        }
+    }
+
+    let best = (0..3)
+        .min_by(|&a, &b| costs[a].total_cmp(&costs[b]))
+        .unwrap_or(0);
+    simplex[best]
+}
@@ -0,0 +1,81 @@
+use std::iter::FusedIterator;
+
+use crate::evaluator::SpringEvaluator;
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The epsilon used by [`Spring::settling_duration`].
+const DEFAULT_EPSILON: f64 = 0.001;
+
+/// An iterator over the frames of a spring's motion, created with
+/// [`Spring::frames`].
+///
+/// Each item is a `(time, value, velocity)` tuple. The iterator ends after the
+/// first frame at which the spring [is settled](Spring::is_settled), so a
+/// spring that never settles produces frames forever.
+#[derive(Debug, Clone)]
+pub struct SpringFrames<V> {
+    evaluator: SpringEvaluator<V>,
+    frame_duration: f64,
+    epsilon: f64,
+    index: u64,
+    finished: bool,
+}
+
+impl Spring {
+    /// Returns an iterator over the motion of the spring given a target
+    /// amount of change, sampled every `frame_duration` seconds starting at
+    /// time 0.
+    ///
+    /// The iterator ends once the spring settles within an epsilon of 0.001,
+    /// which can be changed with [`SpringFrames::epsilon`].
+    pub fn frames<V>(&self, target: V, initial_velocity: V, frame_duration: f64) -> SpringFrames<V>
+    where
+        V: VectorArithmetic,
+    {
+        SpringFrames {
+            evaluator: self.evaluator(target, initial_velocity),
+            frame_duration,
+            epsilon: DEFAULT_EPSILON,
+            index: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<V> SpringFrames<V> {
+    /// Sets the threshold passed to [`Spring::is_settled`] to decide when the
+    /// iterator ends.
+    #[inline]
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl<V> Iterator for SpringFrames<V>
+where
+    V: VectorArithmetic,
+{
+    type Item = (f64, V, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let time = self.index as f64 * self.frame_duration;
+        self.index += 1;
+
+        let value = self.evaluator.value_at(time);
+        let velocity = self.evaluator.velocity_at(time);
+        self.finished = self.evaluator.spring().is_settled(
+            &value,
+            &velocity,
+            self.evaluator.target(),
+            self.epsilon,
+        );
+        Some((time, value, velocity))
+    }
+}
+
+impl<V> FusedIterator for SpringFrames<V> where V: VectorArithmetic {}
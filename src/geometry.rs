@@ -0,0 +1,203 @@
+use crate::modular::shortest_difference;
+use crate::vector_arithmetic::componentwise_vector_arithmetic_impl;
+
+/// A point in a two-dimensional coordinate system.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    /// The point at the origin.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    #[inline]
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A width and height.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Size {
+    /// The size whose width and height are both zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    #[inline]
+    pub const fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+}
+
+/// A rectangle defined by its origin and size.
+///
+/// Animating a rectangle springs its origin and size independently, which
+/// keeps both edges of the frame moving smoothly.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub origin: Point,
+    pub size: Size,
+}
+
+impl Rect {
+    /// The rectangle whose origin and size are both zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    #[inline]
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            origin: Point::new(x, y),
+            size: Size::new(width, height),
+        }
+    }
+
+    #[inline]
+    pub fn min_x(&self) -> f64 {
+        self.origin.x
+    }
+
+    #[inline]
+    pub fn min_y(&self) -> f64 {
+        self.origin.y
+    }
+
+    #[inline]
+    pub fn max_x(&self) -> f64 {
+        self.origin.x + self.size.width
+    }
+
+    #[inline]
+    pub fn max_y(&self) -> f64 {
+        self.origin.y + self.size.height
+    }
+
+    /// The point at the center of the rectangle.
+    #[inline]
+    pub fn center(&self) -> Point {
+        Point::new(
+            self.origin.x + self.size.width / 2.0,
+            self.origin.y + self.size.height / 2.0,
+        )
+    }
+}
+
+/// The inset distances for the sides of a rectangle.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeInsets {
+    pub top: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub right: f64,
+}
+
+impl EdgeInsets {
+    /// The insets whose distances are all zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    #[inline]
+    pub const fn new(top: f64, left: f64, bottom: f64, right: f64) -> Self {
+        Self {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+}
+
+/// A point in polar coordinates.
+///
+/// Springing a polar value moves the angle and radius independently, so an
+/// element orbiting a center follows the arc instead of cutting across the
+/// circle as it would when springing `x` and `y`. The angle is not wrapped,
+/// for the reasons given on [`Modular`]; pass the target through
+/// [`closest`](Self::closest) to travel along the shorter arc.
+///
+/// [`Modular`]: crate::Modular
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polar {
+    /// The counterclockwise angle from the positive x-axis, in radians.
+    pub angle: f64,
+    pub radius: f64,
+}
+
+impl Polar {
+    /// The polar point at the origin.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    #[inline]
+    pub const fn new(angle: f64, radius: f64) -> Self {
+        Self { angle, radius }
+    }
+
+    /// Creates a polar point from a point in Cartesian coordinates.
+    #[inline]
+    pub fn from_point(point: Point) -> Self {
+        Self::new(point.y.atan2(point.x), point.x.hypot(point.y))
+    }
+
+    /// Converts the polar point into Cartesian coordinates.
+    #[inline]
+    pub fn to_point(&self) -> Point {
+        let (sin, cos) = self.angle.sin_cos();
+        Point::new(self.radius * cos, self.radius * sin)
+    }
+
+    /// Returns `target` with its angle shifted by whole turns so that it is
+    /// within half a turn of this point's angle.
+    pub fn closest(&self, target: Self) -> Self {
+        let difference = shortest_difference(self.angle, target.angle, std::f64::consts::TAU);
+        Self::new(self.angle + difference, target.radius)
+    }
+}
+
+impl From<Point> for Polar {
+    #[inline]
+    fn from(point: Point) -> Self {
+        Self::from_point(point)
+    }
+}
+
+impl From<Polar> for Point {
+    #[inline]
+    fn from(polar: Polar) -> Self {
+        polar.to_point()
+    }
+}
+
+componentwise_vector_arithmetic_impl! {
+    Point { x: f64, y: f64 }
+    Polar { angle: f64, radius: f64 }
+    Size { width: f64, height: f64 }
+    Rect { origin: Point, size: Size }
+    EdgeInsets { top: f64, left: f64, bottom: f64, right: f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    use super::*;
+
+    #[test]
+    fn closest_takes_the_shorter_arc() {
+        let point = Polar::new(PI - 0.1, 1.0);
+        let closest = point.closest(Polar::new(-PI + 0.1, 2.0));
+        assert!((closest.angle - (PI + 0.1)).abs() < 1e-12);
+        assert_eq!(closest.radius, 2.0);
+        assert!((point.closest(Polar::new(FRAC_PI_2, 1.0)).angle - FRAC_PI_2).abs() < 1e-12);
+        let wound = Polar::new(2.0 * TAU, 1.0).closest(Polar::new(0.1, 1.0));
+        assert!((wound.angle - (2.0 * TAU + 0.1)).abs() < 1e-12);
+    }
+}
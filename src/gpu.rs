@@ -0,0 +1,155 @@
+use crate::spring::Spring;
+
+/// The parameters of a spring in the layout of a shader uniform, as returned
+/// by [`Spring::gpu_coefficients`].
+///
+/// The struct is `#[repr(C)]` and 16 bytes of `f32`s, which matches a `vec4`
+/// in both WGSL and GLSL `std140` layouts. It is evaluated in a shader by the
+/// functions in [`SpringGpuUniform::shader_source`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SpringGpuUniform {
+    pub angular_frequency: f32,
+    pub decay_constant: f32,
+    pub mass: f32,
+    /// The [settling duration](Spring::settling_duration) of the spring,
+    /// after which a shader can treat the motion as complete.
+    pub settling_duration: f32,
+}
+
+/// A shading language that [`SpringGpuUniform::shader_source`] can generate
+/// code for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderLanguage {
+    Wgsl,
+    Glsl,
+}
+
+const WGSL_SOURCE: &str = "\
+struct SpringUniform {
+    angular_frequency: f32,
+    decay_constant: f32,
+    mass: f32,
+    settling_duration: f32,
+}
+
+fn spring_value(spring: SpringUniform, delta: f32, initial_velocity: f32, elapsed: f32) -> f32 {
+    let w = spring.angular_frequency;
+    let d = spring.decay_constant;
+    if w > 0.0 {
+        let displacement = (delta * d - initial_velocity) * sin(w * elapsed) / w
+            + delta * cos(w * elapsed);
+        return delta - displacement * exp(-d * elapsed);
+    }
+    if w < 0.0 {
+        let slow = -w - d;
+        let fast = w - d;
+        let slow_term = exp(slow * elapsed);
+        let fast_term = exp(fast * elapsed);
+        let scale = ((d - w) * slow_term + slow * fast_term) / (2.0 * w) + 1.0;
+        return delta * scale - initial_velocity * (slow_term - fast_term) / (2.0 * w);
+    }
+    return delta - (delta + (delta * d - initial_velocity) * elapsed) * exp(-d * elapsed);
+}
+
+fn spring_velocity(spring: SpringUniform, delta: f32, initial_velocity: f32, elapsed: f32) -> f32 {
+    let w = spring.angular_frequency;
+    let d = spring.decay_constant;
+    if w > 0.0 {
+        let decay = exp(-d * elapsed);
+        let s = sin(w * elapsed);
+        let c = cos(w * elapsed);
+        return (delta * d - initial_velocity) * (d * s - w * c) * decay / w
+            + delta * (w * s + d * c) * decay;
+    }
+    if w < 0.0 {
+        let slow = -w - d;
+        let fast = w - d;
+        let slow_term = slow * exp(slow * elapsed);
+        let fast_term = fast * exp(fast * elapsed);
+        let scale = ((d - w) * slow_term + slow * fast_term) / (2.0 * w);
+        return delta * scale - initial_velocity * (slow_term - fast_term) / (2.0 * w);
+    }
+    let decay = exp(-d * elapsed);
+    return (delta * d - initial_velocity) * (d * elapsed - 1.0) * decay + delta * d * decay;
+}
+";
+
+const GLSL_SOURCE: &str = "\
+struct SpringUniform {
+    float angular_frequency;
+    float decay_constant;
+    float mass;
+    float settling_duration;
+};
+
+float spring_value(SpringUniform spring, float delta, float initial_velocity, float elapsed) {
+    float w = spring.angular_frequency;
+    float d = spring.decay_constant;
+    if (w > 0.0) {
+        float displacement = (delta * d - initial_velocity) * sin(w * elapsed) / w
+            + delta * cos(w * elapsed);
+        return delta - displacement * exp(-d * elapsed);
+    }
+    if (w < 0.0) {
+        float slow = -w - d;
+        float fast = w - d;
+        float slow_term = exp(slow * elapsed);
+        float fast_term = exp(fast * elapsed);
+        float scale = ((d - w) * slow_term + slow * fast_term) / (2.0 * w) + 1.0;
+        return delta * scale - initial_velocity * (slow_term - fast_term) / (2.0 * w);
+    }
+    return delta - (delta + (delta * d - initial_velocity) * elapsed) * exp(-d * elapsed);
+}
+
+float spring_velocity(SpringUniform spring, float delta, float initial_velocity, float elapsed) {
+    float w = spring.angular_frequency;
+    float d = spring.decay_constant;
+    if (w > 0.0) {
+        float decay = exp(-d * elapsed);
+        float s = sin(w * elapsed);
+        float c = cos(w * elapsed);
+        return (delta * d - initial_velocity) * (d * s - w * c) * decay / w
+            + delta * (w * s + d * c) * decay;
+    }
+    if (w < 0.0) {
+        float slow = -w - d;
+        float fast = w - d;
+        float slow_term = slow * exp(slow * elapsed);
+        float fast_term = fast * exp(fast * elapsed);
+        float scale = ((d - w) * slow_term + slow * fast_term) / (2.0 * w);
+        return delta * scale - initial_velocity * (slow_term - fast_term) / (2.0 * w);
+    }
+    float decay = exp(-d * elapsed);
+    return (delta * d - initial_velocity) * (d * elapsed - 1.0) * decay + delta * d * decay;
+}
+";
+
+impl Spring {
+    /// Returns the parameters of the spring in single precision, laid out for
+    /// a shader uniform or storage buffer.
+    pub fn gpu_coefficients(&self) -> SpringGpuUniform {
+        SpringGpuUniform {
+            angular_frequency: self.angular_frequency as f32,
+            decay_constant: self.decay_constant as f32,
+            mass: self.mass as f32,
+            settling_duration: self.settling_duration() as f32,
+        }
+    }
+}
+
+impl SpringGpuUniform {
+    /// Returns shader code declaring a `SpringUniform` struct with the layout
+    /// of this type, and the functions `spring_value` and `spring_velocity`.
+    ///
+    /// The functions take the uniform, the amount of change, the initial
+    /// velocity and the elapsed time, and evaluate the same closed-form
+    /// motion as [`Spring::value`] and [`Spring::velocity`].
+    pub const fn shader_source(language: ShaderLanguage) -> &'static str {
+        match language {
+            ShaderLanguage::Wgsl => WGSL_SOURCE,
+            ShaderLanguage::Glsl => GLSL_SOURCE,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::spring::Spring;
+use crate::spring32::Spring32;
+
+macro_rules! approx_spring_impl {
+    ($spring:ty, $scalar:ty) => {
+        impl AbsDiffEq for $spring {
+            type Epsilon = $scalar;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                <$scalar>::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.angular_frequency
+                    .abs_diff_eq(&other.angular_frequency, epsilon)
+                    && self
+                        .decay_constant
+                        .abs_diff_eq(&other.decay_constant, epsilon)
+                    && self.mass.abs_diff_eq(&other.mass, epsilon)
+            }
+        }
+
+        impl RelativeEq for $spring {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                <$scalar>::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.angular_frequency
+                    .relative_eq(&other.angular_frequency, epsilon, max_relative)
+                    && self
+                        .decay_constant
+                        .relative_eq(&other.decay_constant, epsilon, max_relative)
+                    && self.mass.relative_eq(&other.mass, epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $spring {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$scalar>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.angular_frequency
+                    .ulps_eq(&other.angular_frequency, epsilon, max_ulps)
+                    && self
+                        .decay_constant
+                        .ulps_eq(&other.decay_constant, epsilon, max_ulps)
+                    && self.mass.ulps_eq(&other.mass, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+approx_spring_impl!(Spring, f64);
+approx_spring_impl!(Spring32, f32);
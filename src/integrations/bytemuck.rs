@@ -0,0 +1,16 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::gpu::SpringGpuUniform;
+use crate::spring::Spring;
+use crate::spring32::Spring32;
+
+// SAFETY: All three types are `#[repr(C)]` structs made only of floats of the
+// same width, so they have no padding and every bit pattern is a valid value.
+unsafe impl Zeroable for Spring {}
+unsafe impl Pod for Spring {}
+
+unsafe impl Zeroable for Spring32 {}
+unsafe impl Pod for Spring32 {}
+
+unsafe impl Zeroable for SpringGpuUniform {}
+unsafe impl Pod for SpringGpuUniform {}
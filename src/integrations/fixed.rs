@@ -0,0 +1,32 @@
+use fixed::types::extra::{LeEqU16, LeEqU32, LeEqU64};
+use fixed::{FixedI16, FixedI32, FixedI64};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+// Scaling goes through f64 and saturates at the representable range, so a
+// large overshoot clamps instead of panicking on overflow.
+macro_rules! fixed_vector_arithmetic_impl {
+    ($($t:ident: $frac:ident)*) => ($(
+        impl<Frac: $frac> AdditiveArithmetic for $t<Frac> {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl<Frac: $frac> VectorArithmetic for $t<Frac> {
+            fn magnitude_squared(&self) -> f64 {
+                let value: f64 = self.to_num();
+                value * value
+            }
+
+            fn scale_by(&mut self, scalar: f64) {
+                *self = Self::saturating_from_num(self.to_num::<f64>() * scalar);
+            }
+
+            fn dot(&self, other: &Self) -> f64 {
+                self.to_num::<f64>() * other.to_num::<f64>()
+            }
+        }
+    )*)
+}
+
+fixed_vector_arithmetic_impl! { FixedI16: LeEqU16 FixedI32: LeEqU32 FixedI64: LeEqU64 }
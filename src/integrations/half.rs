@@ -0,0 +1,31 @@
+use half::{bf16, f16};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+// Half-precision values are widened to f64 for every operation, so only the
+// stored result is rounded to 16 bits.
+macro_rules! half_vector_arithmetic_impl {
+    ($($t:ty)*) => ($(
+        impl AdditiveArithmetic for $t {
+            const ZERO: Self = <$t>::ZERO;
+        }
+
+        impl VectorArithmetic for $t {
+            fn magnitude_squared(&self) -> f64 {
+                let value = self.to_f64();
+                value * value
+            }
+
+            fn scale_by(&mut self, scalar: f64) {
+                *self = <$t>::from_f64(self.to_f64() * scalar);
+            }
+
+            fn dot(&self, other: &Self) -> f64 {
+                self.to_f64() * other.to_f64()
+            }
+        }
+    )*)
+}
+
+half_vector_arithmetic_impl! { f16 bf16 }
@@ -0,0 +1,58 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::dyn_vector::padded_vector_arithmetic_impl;
+
+/// A fixed-capacity vector of components stored inline, which can be animated
+/// without any heap allocation.
+///
+/// Vectors of different lengths are combined like [`DynVector`]: the shorter
+/// operand is treated as if it were padded with zeros.
+///
+/// [`DynVector`]: crate::DynVector
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HeaplessVector<const N: usize>(pub heapless::Vec<f64, N>);
+
+impl<const N: usize> HeaplessVector<N> {
+    /// Creates a vector from the given components.
+    #[inline]
+    pub const fn new(components: heapless::Vec<f64, N>) -> Self {
+        Self(components)
+    }
+
+    /// Consumes the vector, returning its components.
+    #[inline]
+    pub fn into_inner(self) -> heapless::Vec<f64, N> {
+        self.0
+    }
+}
+
+impl<const N: usize> From<heapless::Vec<f64, N>> for HeaplessVector<N> {
+    #[inline]
+    fn from(components: heapless::Vec<f64, N>) -> Self {
+        Self(components)
+    }
+}
+
+impl<const N: usize> Deref for HeaplessVector<N> {
+    type Target = [f64];
+
+    #[inline]
+    fn deref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for HeaplessVector<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
+// Both operands hold at most `N` components, so padding to the longer length
+// always fits within the capacity.
+padded_vector_arithmetic_impl! {
+    [const N: usize] HeaplessVector<N>,
+    zero: Self(heapless::Vec::new()),
+    resize: |components, len| _ = components.resize(len, 0.0)
+}
@@ -0,0 +1,185 @@
+use kurbo::{Affine, BezPath, ParamCurve, ParamCurveArclen, PathEl, PathSeg, Point, Vec2};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::dyn_vector::padded_vector_arithmetic_impl;
+use crate::transform::{DecomposedTransform2D, Transform2D};
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl AdditiveArithmetic for Vec2 {
+    const ZERO: Self = Vec2::ZERO;
+}
+
+impl VectorArithmetic for Vec2 {
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.hypot2()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        *self *= scalar;
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        Vec2::dot(*self, *other)
+    }
+}
+
+// `Affine` stores its coefficients in the same order as `Transform2D`, so
+// canvas transforms are animated by springing a `DecomposedTransform2D`.
+
+impl From<Affine> for Transform2D {
+    #[inline]
+    fn from(affine: Affine) -> Self {
+        let [a, b, c, d, tx, ty] = affine.as_coeffs();
+        Self::new(a, b, c, d, tx, ty)
+    }
+}
+
+impl From<Transform2D> for Affine {
+    #[inline]
+    fn from(transform: Transform2D) -> Self {
+        Affine::new([
+            transform.a,
+            transform.b,
+            transform.c,
+            transform.d,
+            transform.tx,
+            transform.ty,
+        ])
+    }
+}
+
+impl From<Affine> for DecomposedTransform2D {
+    #[inline]
+    fn from(affine: Affine) -> Self {
+        Transform2D::from(affine).decompose()
+    }
+}
+
+impl From<DecomposedTransform2D> for Affine {
+    #[inline]
+    fn from(decomposed: DecomposedTransform2D) -> Self {
+        decomposed.recompose().into()
+    }
+}
+
+/// The accuracy used when measuring and inverting arc lengths.
+const ARCLEN_ACCURACY: f64 = 1e-4;
+
+/// A shape that can be morphed by a spring, represented as a list of points
+/// sampled along a path.
+///
+/// Two paths can only be interpolated point by point once they have the same
+/// number of points, so each path is resampled to a fixed count at equal
+/// arc-length intervals with [`resample`](Self::resample). The resampled
+/// shapes can then be driven by a [`Spring`](crate::Spring) like any other
+/// value and converted back with [`to_bez_path`](Self::to_bez_path).
+///
+/// Shapes with different point counts are combined like
+/// [`DynVector`](crate::DynVector), by padding the shorter one with points at
+/// the origin.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PathAnimatable(Vec<f64>);
+
+impl PathAnimatable {
+    /// Samples `count` points along `path`, evenly spaced by arc length.
+    ///
+    /// The first and last points are the start and end of the path. Multiple
+    /// subpaths are measured as if they were joined end to end.
+    pub fn resample(path: &BezPath, count: usize) -> Self {
+        let segments: Vec<(PathSeg, f64)> = path
+            .segments()
+            .map(|segment| (segment, segment.arclen(ARCLEN_ACCURACY)))
+            .collect();
+
+        let Some(&(last_segment, _)) = segments.last() else {
+            let start = path
+                .elements()
+                .iter()
+                .find_map(|element| match element {
+                    PathEl::MoveTo(point) => Some(*point),
+                    _ => None,
+                })
+                .unwrap_or(Point::ORIGIN);
+            return Self::from_points(std::iter::repeat_n(start, count));
+        };
+
+        let total_length: f64 = segments.iter().map(|(_, length)| length).sum();
+        let mut index = 0;
+        let mut segment_start = 0.0;
+        let points = (0..count).map(|sample| {
+            if sample + 1 == count && count > 1 {
+                return last_segment.end();
+            }
+            let distance = if count > 1 {
+                total_length * sample as f64 / (count - 1) as f64
+            } else {
+                0.0
+            };
+            while index + 1 < segments.len() && distance > segment_start + segments[index].1 {
+                segment_start += segments[index].1;
+                index += 1;
+            }
+            let (segment, length) = segments[index];
+            let local_distance = (distance - segment_start).clamp(0.0, length);
+            let t = if length > 0.0 {
+                segment.inv_arclen(local_distance, ARCLEN_ACCURACY)
+            } else {
+                0.0
+            };
+            segment.eval(t)
+        });
+        Self::from_points(points)
+    }
+
+    /// Creates a shape from the given points.
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Self {
+        Self(
+            points
+                .into_iter()
+                .flat_map(|point| [point.x, point.y])
+                .collect(),
+        )
+    }
+
+    /// The number of points in the shape.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len() / 2
+    }
+
+    /// Returns `true` if the shape has no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the points of the shape.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.0
+            .chunks_exact(2)
+            .map(|point| Point::new(point[0], point[1]))
+    }
+
+    /// Connects the points with straight lines, optionally closing the path.
+    pub fn to_bez_path(&self, closed: bool) -> BezPath {
+        let mut path = BezPath::new();
+        let mut points = self.points();
+        if let Some(first) = points.next() {
+            path.move_to(first);
+            for point in points {
+                path.line_to(point);
+            }
+            if closed {
+                path.close_path();
+            }
+        }
+        path
+    }
+}
+
+padded_vector_arithmetic_impl! {
+    [] PathAnimatable, zero: Self(Vec::new()), resize: |components, len| components.resize(len, 0.0)
+}
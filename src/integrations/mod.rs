@@ -0,0 +1,45 @@
+//! Arithmetic trait implementations for types from optional dependencies.
+
+#[cfg(feature = "heapless")]
+pub use self::heapless::HeaplessVector;
+#[cfg(feature = "kurbo")]
+pub use self::kurbo::PathAnimatable;
+#[cfg(feature = "nalgebra")]
+pub use self::nalgebra::DynMatrix;
+#[cfg(feature = "serde")]
+pub use self::serde::{SerializeSpringAs, SpringRepresentation};
+#[cfg(feature = "smallvec")]
+pub use self::smallvec::SmallVector;
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "kurbo")]
+mod kurbo;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rgb")]
+mod rgb;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "wide")]
+mod wide;
@@ -0,0 +1,236 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use nalgebra::{ArrayStorage, DMatrix, DVector, SMatrix};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::dyn_vector::DynVector;
+use crate::vector_arithmetic::VectorArithmetic;
+
+macro_rules! static_matrix_vector_arithmetic_impl {
+    ($($t:ty)*) => ($(
+        impl<const R: usize, const C: usize> AdditiveArithmetic for SMatrix<$t, R, C> {
+            const ZERO: Self = SMatrix::from_array_storage(ArrayStorage([[0.0; R]; C]));
+        }
+
+        impl<const R: usize, const C: usize> VectorArithmetic for SMatrix<$t, R, C> {
+            #[inline]
+            fn magnitude_squared(&self) -> f64 {
+                self.iter().map(|&element| (element as f64) * (element as f64)).sum()
+            }
+
+            #[inline]
+            fn scale_by(&mut self, scalar: f64) {
+                *self *= scalar as $t;
+            }
+
+            #[inline]
+            fn dot(&self, other: &Self) -> f64 {
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(&lhs, &rhs)| (lhs as f64) * (rhs as f64))
+                    .sum()
+            }
+        }
+    )*)
+}
+
+static_matrix_vector_arithmetic_impl! { f32 f64 }
+
+impl From<DVector<f64>> for DynVector {
+    #[inline]
+    fn from(vector: DVector<f64>) -> Self {
+        Self(vector.data.into())
+    }
+}
+
+impl From<DynVector> for DVector<f64> {
+    #[inline]
+    fn from(vector: DynVector) -> Self {
+        DVector::from_vec(vector.0)
+    }
+}
+
+/// A dynamically-sized matrix that can be animated, convertible to and from
+/// nalgebra's [`DMatrix`].
+///
+/// `DMatrix` itself cannot provide a constant zero value, and its arithmetic
+/// panics when dimensions differ. `DynMatrix` follows the same policy as
+/// [`DynVector`] instead: when shapes differ, each operand is treated as if it
+/// were padded with zeros to the larger number of rows and columns, and the
+/// result has that shape. The zero value is the empty matrix.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DynMatrix {
+    nrows: usize,
+    ncols: usize,
+    /// The elements in column-major order.
+    data: Vec<f64>,
+}
+
+impl DynMatrix {
+    /// Creates a matrix from elements stored in column-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data.len()` is not `nrows * ncols`.
+    pub fn from_column_slice(nrows: usize, ncols: usize, data: &[f64]) -> Self {
+        assert_eq!(data.len(), nrows * ncols, "data does not match shape");
+        Self {
+            nrows,
+            ncols,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Creates a matrix of the given shape with every element set to zero.
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        Self {
+            nrows,
+            ncols,
+            data: vec![0.0; nrows * ncols],
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The elements in column-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Grows the matrix with zeros so that it has at least the given shape.
+    fn pad_to(&mut self, nrows: usize, ncols: usize) {
+        if nrows <= self.nrows && ncols <= self.ncols {
+            return;
+        }
+        let nrows = nrows.max(self.nrows);
+        let ncols = ncols.max(self.ncols);
+        let mut data = vec![0.0; nrows * ncols];
+        for column in 0..self.ncols {
+            let source = &self.data[column * self.nrows..(column + 1) * self.nrows];
+            data[column * nrows..column * nrows + self.nrows].copy_from_slice(source);
+        }
+        *self = Self { nrows, ncols, data };
+    }
+
+    /// Applies `operation` to each element of `self` and the corresponding
+    /// element of `rhs`, padding as needed.
+    fn combine(&mut self, rhs: &Self, operation: impl Fn(&mut f64, f64)) {
+        self.pad_to(rhs.nrows, rhs.ncols);
+        for column in 0..rhs.ncols {
+            for row in 0..rhs.nrows {
+                let element = rhs.data[column * rhs.nrows + row];
+                operation(&mut self.data[column * self.nrows + row], element);
+            }
+        }
+    }
+}
+
+impl From<DMatrix<f64>> for DynMatrix {
+    #[inline]
+    fn from(matrix: DMatrix<f64>) -> Self {
+        Self {
+            nrows: matrix.nrows(),
+            ncols: matrix.ncols(),
+            data: matrix.data.into(),
+        }
+    }
+}
+
+impl From<DynMatrix> for DMatrix<f64> {
+    #[inline]
+    fn from(matrix: DynMatrix) -> Self {
+        DMatrix::from_vec(matrix.nrows, matrix.ncols, matrix.data)
+    }
+}
+
+impl AddAssign for DynMatrix {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.combine(&rhs, |lhs, rhs| *lhs += rhs);
+    }
+}
+
+impl SubAssign for DynMatrix {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.combine(&rhs, |lhs, rhs| *lhs -= rhs);
+    }
+}
+
+impl Add for DynMatrix {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl Sub for DynMatrix {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl AdditiveArithmetic for DynMatrix {
+    const ZERO: Self = Self {
+        nrows: 0,
+        ncols: 0,
+        data: Vec::new(),
+    };
+}
+
+impl VectorArithmetic for DynMatrix {
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.data.iter().map(|element| element * element).sum()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        for element in &mut self.data {
+            *element *= scalar;
+        }
+    }
+
+    fn dot(&self, other: &Self) -> f64 {
+        // Missing elements are zero, so only the shared shape contributes.
+        let nrows = self.nrows.min(other.nrows);
+        let ncols = self.ncols.min(other.ncols);
+        (0..ncols)
+            .map(|column| {
+                let lhs = &self.data[column * self.nrows..][..nrows];
+                let rhs = &other.data[column * other.nrows..][..nrows];
+                lhs.iter().zip(rhs).map(|(lhs, rhs)| lhs * rhs).sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_matrix_dot_pads_with_zeros() {
+        let lhs = DynMatrix::from_column_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let rhs = DynMatrix::from_column_slice(3, 1, &[5.0, 6.0, 7.0]);
+        assert_eq!(lhs.dot(&rhs), 1.0 * 5.0 + 2.0 * 6.0);
+        assert_eq!(lhs.dot(&DynMatrix::ZERO), 0.0);
+        assert_eq!(lhs.dot(&lhs), lhs.magnitude_squared());
+    }
+}
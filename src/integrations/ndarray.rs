@@ -0,0 +1,46 @@
+use ndarray::Array1;
+
+use crate::dyn_vector::DynVector;
+
+// `Array1` cannot provide a constant zero value and panics when combining
+// arrays of different lengths, so series are animated as a `DynVector`, which
+// zero-pads instead.
+
+impl From<Array1<f64>> for DynVector {
+    #[inline]
+    fn from(array: Array1<f64>) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl From<&Array1<f64>> for DynVector {
+    #[inline]
+    fn from(array: &Array1<f64>) -> Self {
+        array.iter().copied().collect()
+    }
+}
+
+impl From<DynVector> for Array1<f64> {
+    #[inline]
+    fn from(vector: DynVector) -> Self {
+        Array1::from_vec(vector.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Spring;
+
+    #[test]
+    fn animates_a_series_through_dyn_vector() {
+        let mut value = DynVector::from(Array1::from_vec(vec![0.0, 1.0]));
+        let mut velocity = DynVector::zeros(2);
+        let target = DynVector::from(&Array1::from_vec(vec![2.0, -1.0]));
+        for _ in 0..600 {
+            Spring::snappy().update(&mut value, &mut velocity, target.clone(), 1.0 / 60.0);
+        }
+        let series = Array1::from(value);
+        assert!((series[0] - 2.0).abs() < 1e-3 && (series[1] + 1.0).abs() < 1e-3);
+    }
+}
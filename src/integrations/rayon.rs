@@ -0,0 +1,136 @@
+use rayon::prelude::*;
+
+use crate::batch::SpringBatch;
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// The number of samples computed by each parallel task, which keeps the
+/// scheduling overhead small relative to the work.
+const SAMPLES_PER_TASK: usize = 1024;
+
+impl SpringBatch {
+    /// Calculates the value of every spring at a given time, like
+    /// [`values`](Self::values), in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn par_values(
+        &self,
+        targets: &[f64],
+        initial_velocities: &[f64],
+        time: f64,
+        output: &mut [f64],
+    ) {
+        self.assert_len(&[targets.len(), initial_velocities.len(), output.len()]);
+        output
+            .par_chunks_mut(SAMPLES_PER_TASK)
+            .enumerate()
+            .for_each(|(task, output)| {
+                let start = task * SAMPLES_PER_TASK;
+                self.evaluate(
+                    start..start + output.len(),
+                    time,
+                    |index| (targets[index], initial_velocities[index]),
+                    |index, value, _| output[index - start] = value,
+                );
+            });
+    }
+
+    /// Calculates the velocity of every spring at a given time, like
+    /// [`velocities`](Self::velocities), in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn par_velocities(
+        &self,
+        targets: &[f64],
+        initial_velocities: &[f64],
+        time: f64,
+        output: &mut [f64],
+    ) {
+        self.assert_len(&[targets.len(), initial_velocities.len(), output.len()]);
+        output
+            .par_chunks_mut(SAMPLES_PER_TASK)
+            .enumerate()
+            .for_each(|(task, output)| {
+                let start = task * SAMPLES_PER_TASK;
+                self.evaluate(
+                    start..start + output.len(),
+                    time,
+                    |index| (targets[index], initial_velocities[index]),
+                    |index, _, velocity| output[index - start] = velocity,
+                );
+            });
+    }
+
+    /// Updates the current value and velocity of every spring, like
+    /// [`update`](Self::update), in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any slice is not as long as the batch.
+    pub fn par_update(
+        &self,
+        values: &mut [f64],
+        velocities: &mut [f64],
+        targets: &[f64],
+        delta_time: f64,
+    ) {
+        self.assert_len(&[values.len(), velocities.len(), targets.len()]);
+        values
+            .par_chunks_mut(SAMPLES_PER_TASK)
+            .zip(velocities.par_chunks_mut(SAMPLES_PER_TASK))
+            .enumerate()
+            .for_each(|(task, (values, velocities))| {
+                let start = task * SAMPLES_PER_TASK;
+                self.update_range(
+                    start..start + values.len(),
+                    values,
+                    velocities,
+                    targets,
+                    delta_time,
+                );
+            });
+    }
+}
+
+impl Spring {
+    /// Returns `count` values of the spring at evenly spaced times, like
+    /// [`samples`](Self::samples), computed in parallel.
+    pub fn par_samples<V>(
+        &self,
+        target: V,
+        initial_velocity: V,
+        delta_time: f64,
+        count: usize,
+    ) -> Vec<V>
+    where
+        V: VectorArithmetic + Send + Sync,
+    {
+        let mut samples = vec![V::ZERO; count];
+        self.par_sample_into(target, initial_velocity, delta_time, &mut samples);
+        samples
+    }
+
+    /// Fills `output` with values of the spring at evenly spaced times, like
+    /// [`sample_into`](Self::sample_into), computed in parallel.
+    pub fn par_sample_into<V>(
+        &self,
+        target: V,
+        initial_velocity: V,
+        delta_time: f64,
+        output: &mut [V],
+    ) where
+        V: VectorArithmetic + Send + Sync,
+    {
+        let evaluator = self.evaluator(target, initial_velocity);
+        output
+            .par_chunks_mut(SAMPLES_PER_TASK)
+            .enumerate()
+            .for_each(|(chunk, output)| {
+                evaluator.sample_range_into(output, chunk * SAMPLES_PER_TASK, delta_time);
+            });
+    }
+}
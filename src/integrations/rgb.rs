@@ -0,0 +1,77 @@
+use rgb::{RGB, RGBA};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl<T> AdditiveArithmetic for RGB<T>
+where
+    T: AdditiveArithmetic + Copy,
+{
+    const ZERO: Self = RGB {
+        r: T::ZERO,
+        g: T::ZERO,
+        b: T::ZERO,
+    };
+}
+
+impl<T> VectorArithmetic for RGB<T>
+where
+    T: VectorArithmetic + Copy,
+{
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.r.magnitude_squared() + self.g.magnitude_squared() + self.b.magnitude_squared()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.r.scale_by(scalar);
+        self.g.scale_by(scalar);
+        self.b.scale_by(scalar);
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.r.dot(&other.r) + self.g.dot(&other.g) + self.b.dot(&other.b)
+    }
+}
+
+impl<T, A> AdditiveArithmetic for RGBA<T, A>
+where
+    T: AdditiveArithmetic + Copy,
+    A: AdditiveArithmetic + Copy,
+{
+    const ZERO: Self = RGBA {
+        r: T::ZERO,
+        g: T::ZERO,
+        b: T::ZERO,
+        a: A::ZERO,
+    };
+}
+
+impl<T, A> VectorArithmetic for RGBA<T, A>
+where
+    T: VectorArithmetic + Copy,
+    A: VectorArithmetic + Copy,
+{
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.r.magnitude_squared()
+            + self.g.magnitude_squared()
+            + self.b.magnitude_squared()
+            + self.a.magnitude_squared()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.r.scale_by(scalar);
+        self.g.scale_by(scalar);
+        self.b.scale_by(scalar);
+        self.a.scale_by(scalar);
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.r.dot(&other.r) + self.g.dot(&other.g) + self.b.dot(&other.b) + self.a.dot(&other.a)
+    }
+}
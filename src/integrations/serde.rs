@@ -0,0 +1,288 @@
+use serde::de::Error as _;
+use serde::ser::{Error as _, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::descriptor::SpringDescriptor;
+use crate::spring::Spring;
+
+/// A parameterization that a [`Spring`] can be serialized as.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpringRepresentation {
+    /// The stored `angular_frequency`, `decay_constant` and `mass` fields.
+    ///
+    /// This is the only lossless representation, and the one used by
+    /// `Spring`'s `Serialize` implementation.
+    #[default]
+    Raw,
+    /// The `duration` and `bounce` of the spring.
+    ///
+    /// This parameterization has no mass, so only springs with a unit mass
+    /// can be serialized in it.
+    DurationBounce,
+    /// The `mass`, `stiffness`, `damping` and `allow_over_damping` of the
+    /// spring.
+    MassStiffnessDamping,
+    /// The `response` and `damping_ratio` of the spring.
+    ///
+    /// This parameterization has no mass, so only springs with a unit mass
+    /// can be serialized in it.
+    ResponseDampingRatio,
+}
+
+/// Serializes a [`Spring`] in a chosen [`SpringRepresentation`].
+///
+/// Created by [`Spring::serialize_as`].
+#[derive(Debug, Clone, Copy)]
+pub struct SerializeSpringAs {
+    spring: Spring,
+    representation: SpringRepresentation,
+}
+
+impl Spring {
+    /// Returns a value that serializes this spring in the given
+    /// representation.
+    ///
+    /// Every representation can be deserialized back into a `Spring`.
+    /// Serializing fails if the representation cannot express the mass of
+    /// the spring.
+    #[inline]
+    pub fn serialize_as(&self, representation: SpringRepresentation) -> SerializeSpringAs {
+        SerializeSpringAs {
+            spring: *self,
+            representation,
+        }
+    }
+}
+
+impl Serialize for SerializeSpringAs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let spring = &self.spring;
+        match self.representation {
+            SpringRepresentation::Raw => spring.serialize(serializer),
+            SpringRepresentation::DurationBounce | SpringRepresentation::ResponseDampingRatio
+                if spring.mass != 1.0 =>
+            {
+                Err(S::Error::custom(
+                    "only springs with a unit mass can be serialized without a `mass` field",
+                ))
+            }
+            SpringRepresentation::DurationBounce => {
+                let mut state = serializer.serialize_struct("Spring", 2)?;
+                state.serialize_field("duration", &spring.duration())?;
+                state.serialize_field("bounce", &spring.bounce())?;
+                state.end()
+            }
+            SpringRepresentation::MassStiffnessDamping => {
+                let mut state = serializer.serialize_struct("Spring", 4)?;
+                state.serialize_field("mass", &spring.mass)?;
+                state.serialize_field("stiffness", &spring.stiffness())?;
+                state.serialize_field("damping", &spring.damping())?;
+                state.serialize_field("allow_over_damping", &(spring.angular_frequency < 0.0))?;
+                state.end()
+            }
+            SpringRepresentation::ResponseDampingRatio => {
+                let mut state = serializer.serialize_struct("Spring", 2)?;
+                state.serialize_field("response", &spring.response())?;
+                state.serialize_field("damping_ratio", &spring.damping_ratio())?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Every field accepted by any of the parameterizations.
+///
+/// Camel-case aliases are accepted because design tools commonly emit them.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpringFields {
+    #[serde(alias = "angularFrequency")]
+    angular_frequency: Option<f64>,
+    #[serde(alias = "decayConstant")]
+    decay_constant: Option<f64>,
+    mass: Option<f64>,
+    stiffness: Option<f64>,
+    damping: Option<f64>,
+    #[serde(alias = "allowOverDamping")]
+    allow_over_damping: Option<bool>,
+    duration: Option<f64>,
+    bounce: Option<f64>,
+    response: Option<f64>,
+    #[serde(alias = "dampingRatio")]
+    damping_ratio: Option<f64>,
+}
+
+impl<'de> Deserialize<'de> for Spring {
+    /// Deserializes a spring from any of its parameterizations, as described
+    /// by [`SpringDescriptor`]'s `Deserialize` implementation.
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SpringDescriptor::deserialize(deserializer).map(|descriptor| descriptor.to_spring())
+    }
+}
+
+impl Serialize for SpringDescriptor {
+    /// Serializes the fields of the parameterization, without a tag, in the
+    /// same form as [`Spring::serialize_as`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Self::DurationBounce { duration, bounce } => {
+                let mut state = serializer.serialize_struct("Spring", 2)?;
+                state.serialize_field("duration", &duration)?;
+                state.serialize_field("bounce", &bounce)?;
+                state.end()
+            }
+            Self::MassStiffnessDamping {
+                mass,
+                stiffness,
+                damping,
+                allow_over_damping,
+            } => {
+                let mut state = serializer.serialize_struct("Spring", 4)?;
+                state.serialize_field("mass", &mass)?;
+                state.serialize_field("stiffness", &stiffness)?;
+                state.serialize_field("damping", &damping)?;
+                state.serialize_field("allow_over_damping", &allow_over_damping)?;
+                state.end()
+            }
+            Self::ResponseDampingRatio {
+                response,
+                damping_ratio,
+            } => {
+                let mut state = serializer.serialize_struct("Spring", 2)?;
+                state.serialize_field("response", &response)?;
+                state.serialize_field("damping_ratio", &damping_ratio)?;
+                state.end()
+            }
+            Self::Raw(spring) => spring.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SpringDescriptor {
+    /// Deserializes a descriptor, detecting the parameterization from the
+    /// fields that are present:
+    ///
+    /// * `angular_frequency`, `decay_constant` and an optional `mass`;
+    /// * `mass` (optional), `stiffness`, `damping` and an optional
+    ///   `allow_over_damping`;
+    /// * `duration` and an optional `bounce`;
+    /// * `response` and `damping_ratio`.
+    ///
+    /// Fields from different parameterizations cannot be mixed.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = SpringFields::deserialize(deserializer)?;
+        let raw = fields.angular_frequency.is_some() || fields.decay_constant.is_some();
+        let physical = fields.stiffness.is_some()
+            || fields.damping.is_some()
+            || fields.allow_over_damping.is_some();
+        let perceptual = fields.duration.is_some() || fields.bounce.is_some();
+        let response = fields.response.is_some() || fields.damping_ratio.is_some();
+
+        match (raw, physical, perceptual, response) {
+            (true, false, false, false) => Ok(Self::Raw(Spring::new(
+                fields
+                    .angular_frequency
+                    .ok_or_else(|| D::Error::missing_field("angular_frequency"))?,
+                fields
+                    .decay_constant
+                    .ok_or_else(|| D::Error::missing_field("decay_constant"))?,
+                fields.mass.unwrap_or(1.0),
+            ))),
+            (false, true, false, false) => Ok(Self::MassStiffnessDamping {
+                mass: fields.mass.unwrap_or(1.0),
+                stiffness: fields
+                    .stiffness
+                    .ok_or_else(|| D::Error::missing_field("stiffness"))?,
+                damping: fields
+                    .damping
+                    .ok_or_else(|| D::Error::missing_field("damping"))?,
+                allow_over_damping: fields.allow_over_damping.unwrap_or(false),
+            }),
+            (false, false, true, false) if fields.mass.is_none() => Ok(Self::DurationBounce {
+                duration: fields
+                    .duration
+                    .ok_or_else(|| D::Error::missing_field("duration"))?,
+                bounce: fields.bounce.unwrap_or(0.0),
+            }),
+            (false, false, false, true) if fields.mass.is_none() => {
+                Ok(Self::ResponseDampingRatio {
+                    response: fields
+                        .response
+                        .ok_or_else(|| D::Error::missing_field("response"))?,
+                    damping_ratio: fields
+                        .damping_ratio
+                        .ok_or_else(|| D::Error::missing_field("damping_ratio"))?,
+                })
+            }
+            (false, false, false, false) => Err(D::Error::custom(
+                "expected the parameters of a spring, such as `duration` and `bounce`",
+            )),
+            _ => Err(D::Error::custom(
+                "parameters from different spring parameterizations cannot be mixed",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPRESENTATIONS: [SpringRepresentation; 4] = [
+        SpringRepresentation::Raw,
+        SpringRepresentation::DurationBounce,
+        SpringRepresentation::MassStiffnessDamping,
+        SpringRepresentation::ResponseDampingRatio,
+    ];
+
+    fn round_trip(spring: Spring, representation: SpringRepresentation) -> Spring {
+        let json = serde_json::to_string(&spring.serialize_as(representation)).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn every_representation_round_trips() {
+        let springs = [
+            ("underdamped", Spring::with_duration_bounce(0.5, 0.3)),
+            ("critically damped", Spring::with_duration_bounce(0.5, 0.0)),
+            ("overdamped", Spring::with_duration_bounce(0.5, -0.4)),
+            ("overdamped raw", Spring::new(-3.0, 5.0, 1.0)),
+        ];
+        for (name, spring) in springs {
+            for representation in REPRESENTATIONS {
+                let decoded = round_trip(spring, representation);
+                assert!(
+                    decoded.approx_eq(&spring, 1e-9),
+                    "{name} spring as {representation:?}: {decoded:?} != {spring:?}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mass_round_trips_where_it_can_be_represented() {
+        let spring = Spring::with_mass_stiffness_damping(3.0, 120.0, 4.0, false);
+        for representation in [
+            SpringRepresentation::Raw,
+            SpringRepresentation::MassStiffnessDamping,
+        ] {
+            assert!(round_trip(spring, representation).approx_eq(&spring, 1e-9));
+        }
+        for representation in [
+            SpringRepresentation::DurationBounce,
+            SpringRepresentation::ResponseDampingRatio,
+        ] {
+            assert!(serde_json::to_string(&spring.serialize_as(representation)).is_err());
+        }
+    }
+
+    #[test]
+    fn overdamping_is_written() {
+        let json = serde_json::to_value(
+            Spring::new(-3.0, 5.0, 1.0).serialize_as(SpringRepresentation::MassStiffnessDamping),
+        )
+        .unwrap();
+        assert_eq!(json["allow_over_damping"], true);
+    }
+}
@@ -0,0 +1,103 @@
+use wide::{f32x8, f64x4};
+
+use crate::batch::Lanes;
+use crate::spring::Spring;
+
+// The remaining distance to the target combines two scalar coefficients, as
+// in `SpringEvaluator`, weighted by functions of time that are evaluated for
+// every lane at once. The coefficients are computed in f64 for both lane
+// types.
+macro_rules! simd_evaluation_impl {
+    ($($value:ident $velocity:ident $basis:ident => $t:ty, $lane:ty, $lanes:literal;)*) => ($(
+        impl Spring {
+            #[doc = concat!(
+                "Calculates the value of the spring at ", $lanes, " times at once given a \
+                 target amount of change.\n\n\
+                 Each lane of the result is the value at the corresponding lane of `times`."
+            )]
+            pub fn $value(&self, target: $lane, initial_velocity: $lane, times: $t) -> $t {
+                let evaluator = self.evaluator(target as f64, initial_velocity as f64);
+                let [first, second, _, _] = self.$basis(times);
+                <$t>::splat(target)
+                    - first * <$t>::splat(evaluator.first as $lane)
+                    - second * <$t>::splat(evaluator.second as $lane)
+            }
+
+            #[doc = concat!(
+                "Calculates the velocity of the spring at ", $lanes, " times at once given \
+                 a target amount of change.\n\n\
+                 Each lane of the result is the velocity at the corresponding lane of `times`."
+            )]
+            pub fn $velocity(&self, target: $lane, initial_velocity: $lane, times: $t) -> $t {
+                let evaluator = self.evaluator(target as f64, initial_velocity as f64);
+                let [_, _, first, second] = self.$basis(times);
+                -(first * <$t>::splat(evaluator.first as $lane)
+                    + second * <$t>::splat(evaluator.second as $lane))
+            }
+
+            /// The weights of the two coefficients in the remaining distance,
+            /// followed by their derivatives.
+            fn $basis(&self, times: $t) -> [$t; 4] {
+                let decay = <$t>::splat(self.decay_constant as $lane);
+                let frequency = self.angular_frequency as $lane;
+                if frequency > 0.0 {
+                    let envelope = (-decay * times).exp();
+                    let (sin, cos) = (<$t>::splat(frequency) * times).sin_cos();
+                    let frequency = <$t>::splat(frequency);
+                    [
+                        envelope * cos,
+                        envelope * sin,
+                        -envelope * (decay * cos + frequency * sin),
+                        envelope * (frequency * cos - decay * sin),
+                    ]
+                } else if frequency < 0.0 {
+                    let frequency = <$t>::splat(frequency);
+                    let (slow_rate, fast_rate) = (-decay - frequency, frequency - decay);
+                    let slow = (slow_rate * times).exp();
+                    let fast = (fast_rate * times).exp();
+                    [slow, fast, slow_rate * slow, fast_rate * fast]
+                } else {
+                    let envelope = (-decay * times).exp();
+                    [
+                        envelope,
+                        envelope * times,
+                        -decay * envelope,
+                        envelope * (<$t>::ONE - decay * times),
+                    ]
+                }
+            }
+        }
+    )*)
+}
+
+simd_evaluation_impl! {
+    value_x4 velocity_x4 basis_x4 => f64x4, f64, "4";
+    value_x8 velocity_x8 basis_x8 => f32x8, f32, "8";
+}
+
+impl Lanes<4> for f64x4 {
+    #[inline]
+    fn splat(value: f64) -> Self {
+        f64x4::splat(value)
+    }
+
+    #[inline]
+    fn from_array(lanes: [f64; 4]) -> Self {
+        f64x4::from(lanes)
+    }
+
+    #[inline]
+    fn to_array(self) -> [f64; 4] {
+        f64x4::to_array(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        f64x4::exp(self)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        f64x4::sin_cos(self)
+    }
+}
@@ -0,0 +1,59 @@
+use std::ops::{Deref, DerefMut};
+
+use smallvec::SmallVec;
+
+use crate::dyn_vector::padded_vector_arithmetic_impl;
+
+/// A vector of components that is stored inline for up to `N` components.
+///
+/// As long as no more than `N` components are animated, evaluating a spring
+/// never allocates. Vectors of different lengths are combined like
+/// [`DynVector`]: the shorter operand is treated as if it were padded with
+/// zeros.
+///
+/// [`DynVector`]: crate::DynVector
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SmallVector<const N: usize>(pub SmallVec<[f64; N]>);
+
+impl<const N: usize> SmallVector<N> {
+    /// Creates a vector from the given components.
+    #[inline]
+    pub const fn new(components: SmallVec<[f64; N]>) -> Self {
+        Self(components)
+    }
+
+    /// Consumes the vector, returning its components.
+    #[inline]
+    pub fn into_inner(self) -> SmallVec<[f64; N]> {
+        self.0
+    }
+}
+
+impl<const N: usize> From<SmallVec<[f64; N]>> for SmallVector<N> {
+    #[inline]
+    fn from(components: SmallVec<[f64; N]>) -> Self {
+        Self(components)
+    }
+}
+
+impl<const N: usize> Deref for SmallVector<N> {
+    type Target = [f64];
+
+    #[inline]
+    fn deref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for SmallVector<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
+padded_vector_arithmetic_impl! {
+    [const N: usize] SmallVector<N>,
+    zero: Self(SmallVec::new_const()),
+    resize: |components, len| components.resize(len, 0.0)
+}
@@ -0,0 +1,87 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::geometry::Point;
+use crate::spring::Spring;
+use crate::subdivision::MAX_INTERVALS;
+
+impl Spring {
+    /// Returns an SVG path of the spring's value curve, starting at rest and
+    /// moving from 0 to 1, over the time `range`, drawn in a box of `width` by
+    /// `height`.
+    ///
+    /// Time runs from left to right, and the value from bottom to top. The
+    /// vertical scale spans 0 to 1 and any overshoot within the range, so the
+    /// whole curve fits in the box. The curve is sampled once per unit of
+    /// width, up to a bounded number of samples, and the path can be used as
+    /// the `d` attribute of a `<path>`. The path is empty if `width` is not
+    /// finite.
+    pub fn to_svg_path(&self, width: f64, height: f64, range: Range<f64>) -> String {
+        let mut path = String::new();
+        for (index, point) in self.preview_points(width, height, range).iter().enumerate() {
+            let command = if index == 0 { 'M' } else { 'L' };
+            let _ = write!(path, "{command}{:.2} {:.2}", point.x, point.y);
+        }
+        path
+    }
+
+    /// Returns the points of the curve drawn by
+    /// [`to_svg_path`](Self::to_svg_path), in the format of the `points`
+    /// attribute of a `<polyline>`.
+    pub fn to_svg_polyline(&self, width: f64, height: f64, range: Range<f64>) -> String {
+        let mut points = String::new();
+        for (index, point) in self.preview_points(width, height, range).iter().enumerate() {
+            let separator = if index == 0 { "" } else { " " };
+            let _ = write!(points, "{separator}{:.2},{:.2}", point.x, point.y);
+        }
+        points
+    }
+
+    /// Samples the value curve over `range` and maps it into the box.
+    fn preview_points(&self, width: f64, height: f64, range: Range<f64>) -> Vec<Point> {
+        if !width.is_finite() {
+            return Vec::new();
+        }
+        let intervals = (width.ceil().max(1.0) as usize).min(MAX_INTERVALS);
+        let span = range.end - range.start;
+        let values: Vec<f64> = (0..=intervals)
+            .map(|index| {
+                let time = range.start + span * (index as f64 / intervals as f64);
+                self.value(1.0, 0.0, time)
+            })
+            .collect();
+        let (low, high) = values
+            .iter()
+            .fold((0.0_f64, 1.0_f64), |(low, high), &value| {
+                (low.min(value), high.max(value))
+            });
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                Point::new(
+                    width * (index as f64 / intervals as f64),
+                    height * (high - value) / (high - low),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_the_samples_of_large_widths() {
+        let spring = Spring::smooth();
+        assert!(
+            spring
+                .to_svg_path(f64::INFINITY, 100.0, 0.0..1.0)
+                .is_empty()
+        );
+        assert!(spring.to_svg_polyline(f64::NAN, 100.0, 0.0..1.0).is_empty());
+        let points = spring.preview_points(1e300, 100.0, 0.0..1.0);
+        assert_eq!(points.len(), MAX_INTERVALS + 1);
+    }
+}
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+use std::ops::{Div, Mul};
+
+use uom::si::f64::Time;
+use uom::si::time::second;
+use uom::si::{Dimension, Quantity, Units};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl<D, U> AdditiveArithmetic for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    D::Kind: uom::marker::Add + uom::marker::AddAssign + uom::marker::Sub,
+    U: Units<f64> + ?Sized,
+{
+    const ZERO: Self = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 0.0,
+    };
+}
+
+impl<D, U> VectorArithmetic for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    D::Kind: uom::marker::Add + uom::marker::AddAssign + uom::marker::Sub,
+    U: Units<f64> + ?Sized,
+{
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.value * self.value
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.value *= scalar;
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.value * other.value
+    }
+}
+
+// The generic spring methods express velocities in the same type as the
+// animated value. These variants keep the dimensions honest: velocities are
+// the quantity divided by time (a `Length` animates with a `Velocity`), and
+// times are `Time` quantities.
+impl Spring {
+    /// Calculates the value of the spring at a given time given a target
+    /// amount of change, with the initial velocity given as a rate quantity.
+    pub fn value_quantity<Q, R>(&self, target: Q, initial_velocity: R, time: Time) -> Q
+    where
+        Q: VectorArithmetic + Div<Time, Output = R>,
+        R: Mul<Time, Output = Q>,
+    {
+        let initial_velocity = initial_velocity * Time::new::<second>(1.0);
+        self.value(target, initial_velocity, time.get::<second>())
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target
+    /// amount of change, as a rate quantity.
+    pub fn velocity_quantity<Q, R>(&self, target: Q, initial_velocity: R, time: Time) -> R
+    where
+        Q: VectorArithmetic + Div<Time, Output = R>,
+        R: Mul<Time, Output = Q>,
+    {
+        let unit_time = Time::new::<second>(1.0);
+        let velocity = self.velocity(target, initial_velocity * unit_time, time.get::<second>());
+        velocity / unit_time
+    }
+
+    /// Updates the current value and rate-quantity velocity of a spring.
+    ///
+    /// See [`Spring::update`].
+    pub fn update_quantity<Q, R>(
+        &self,
+        value: &mut Q,
+        velocity: &mut R,
+        target: Q,
+        delta_time: Time,
+    ) where
+        Q: VectorArithmetic + Div<Time, Output = R>,
+        R: Mul<Time, Output = Q> + Clone,
+    {
+        let unit_time = Time::new::<second>(1.0);
+        let mut velocity_value = velocity.clone() * unit_time;
+        self.update(
+            value,
+            &mut velocity_value,
+            target,
+            delta_time.get::<second>(),
+        );
+        *velocity = velocity_value / unit_time;
+    }
+}
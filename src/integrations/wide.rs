@@ -0,0 +1,38 @@
+use wide::{f32x4, f32x8, f64x2, f64x4};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+// Each lane is treated as an independent component, so a single spring call
+// evaluates the same curve for every lane at once. The magnitude covers all
+// lanes, which makes settling estimates wait for the slowest lane.
+macro_rules! wide_vector_arithmetic_impl {
+    ($($t:ty => $lane:ty)*) => ($(
+        impl AdditiveArithmetic for $t {
+            const ZERO: Self = <$t>::ZERO;
+        }
+
+        impl VectorArithmetic for $t {
+            fn magnitude_squared(&self) -> f64 {
+                self.to_array()
+                    .iter()
+                    .map(|&lane| (lane as f64) * (lane as f64))
+                    .sum()
+            }
+
+            fn scale_by(&mut self, scalar: f64) {
+                *self *= <$t>::splat(scalar as $lane);
+            }
+
+            fn dot(&self, other: &Self) -> f64 {
+                self.to_array()
+                    .iter()
+                    .zip(other.to_array().iter())
+                    .map(|(&lhs, &rhs)| (lhs as f64) * (rhs as f64))
+                    .sum()
+            }
+        }
+    )*)
+}
+
+wide_vector_arithmetic_impl! { f32x4 => f32 f32x8 => f32 f64x2 => f64 f64x4 => f64 }
@@ -0,0 +1,88 @@
+use crate::spring::Spring;
+use crate::subdivision;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl Spring {
+    /// Returns keyframes of the spring's value, such that interpolating
+    /// linearly between consecutive keyframes stays within `max_error` of the
+    /// spring at every time.
+    ///
+    /// Keyframes are placed densely where the curve bends sharply and sparsely
+    /// where it is nearly straight. The first keyframe is at time 0 with a
+    /// value of zero, and the last is at the settling duration with a value of
+    /// exactly `target`. Springs without damping never settle, so their
+    /// keyframes span their [`duration`](Self::duration) instead.
+    ///
+    /// Errors below 10⁻⁹, including zero, negative and NaN errors, are raised
+    /// to 10⁻⁹, and at most 65,537 keyframes are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target amount of change.
+    /// * `initial_velocity` - The initial velocity of the spring.
+    /// * `max_error` - The largest distance allowed between the interpolated
+    ///   keyframes and the spring.
+    pub fn bake_keyframes<V>(&self, target: V, initial_velocity: V, max_error: f64) -> Vec<(f64, V)>
+    where
+        V: VectorArithmetic,
+    {
+        let max_error = subdivision::tolerance(max_error);
+        let settling_duration = self.settling_duration_with_velocity(
+            target.clone(),
+            initial_velocity.clone(),
+            max_error / 2.0,
+        );
+        let settles = settling_duration.is_finite();
+        let duration = if settles {
+            settling_duration
+        } else {
+            self.duration()
+        };
+        let value = |time: f64| self.value(target.clone(), initial_velocity.clone(), time);
+        let keyframe = |time: f64| {
+            if settles && time >= duration {
+                (duration, target.clone())
+            } else {
+                (time, value(time))
+            }
+        };
+
+        subdivision::subdivide(duration, max_error, keyframe, |start, end, amount| {
+            let time = start.0 + (end.0 - start.0) * amount;
+            let interpolated = start.1.clone().interpolated(end.1.clone(), amount);
+            interpolated.distance_to(&value(time))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyframes_stay_within_the_error() {
+        let spring = Spring::bouncy();
+        let keyframes = spring.bake_keyframes(2.0, 1.0, 1e-3);
+        assert_eq!(keyframes[0], (0.0, 0.0));
+        assert_eq!(keyframes.last().unwrap().1, 2.0);
+        for pair in keyframes.windows(2) {
+            let ((start_time, start), (end_time, end)) = (pair[0], pair[1]);
+            for sample in 0..=10 {
+                let amount = f64::from(sample) / 10.0;
+                let time = start_time + (end_time - start_time) * amount;
+                let interpolated = start + (end - start) * amount;
+                assert!((interpolated - spring.value(2.0, 1.0, time)).abs() <= 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_errors_are_bounded() {
+        for max_error in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let keyframes = Spring::bouncy().bake_keyframes(1.0, 0.0, max_error);
+            assert!(keyframes.len() >= 2);
+            assert!(keyframes.len() <= subdivision::MAX_INTERVALS + 1);
+            assert!(keyframes.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+}
@@ -1,7 +1,116 @@
 mod additive_arithmetic;
+mod anchored_spring;
+mod animatable_pair;
+mod animated_value;
+mod animation;
+mod animation_group;
+mod animation_sequence;
+mod batch;
+mod bezier;
+mod bounded_spring;
+mod characteristics;
+mod clamped_spring;
+mod color;
+mod decay;
+mod default_spring;
+mod descriptor;
+mod dyn_vector;
+mod easing;
+mod energy;
+mod error;
+mod evaluator;
+mod export;
+mod external_force;
+mod extrema;
+mod f32_evaluation;
+mod fit;
+mod frames;
+mod geometry;
+mod gpu;
+mod integrations;
+mod keyframes;
+mod math;
+mod modular;
+mod parse;
+mod platform;
+mod presets;
+mod quantizer;
+mod reach;
+mod response;
+mod rotation;
+mod rubber_band;
+mod runge_kutta;
+mod saturating_animatable;
+mod second_order_dynamics;
+mod simulation;
+mod smooth_damp;
+mod snap_points;
 mod spring;
+mod spring32;
+mod spring_builder;
+mod subdivision;
+mod substep;
+mod table;
+mod tracking;
+mod transform;
 mod vector_arithmetic;
 
 pub use additive_arithmetic::AdditiveArithmetic;
+pub use anchored_spring::AnchoredSpring;
+pub use animatable_pair::AnimatablePair;
+pub use animated_value::AnimatedValue;
+pub use animation::{Animation, AnimationEvent, RepeatCount, SpringAnimation};
+pub use animation_group::{AnimationGroup, AnimationMembers};
+pub use animation_sequence::{AnimationSequence, SequenceSegment};
+pub use batch::SpringBatch;
+pub use bezier::CubicBezierSegment;
+pub use bounded_spring::{BoundaryPolicy, BoundedSpring};
+pub use characteristics::SpringCharacteristics;
+pub use clamped_spring::{ClampedEvaluator, ClampedSpring};
+pub use color::Rgba;
+pub use decay::{Decay, DecayHandoff, project, project_vector};
+pub use default_spring::{default_spring, reset_default_spring, set_default_spring};
+pub use descriptor::SpringDescriptor;
+pub use dyn_vector::DynVector;
+pub use energy::SpringEnergy;
+pub use error::{ParseSpringError, SpringError};
+pub use evaluator::SpringEvaluator;
+pub use export::CurveExport;
+pub use external_force::ExternalForceSpring;
+pub use f32_evaluation::F32Vector;
+pub use fit::FitResult;
+pub use frames::SpringFrames;
+pub use geometry::{EdgeInsets, Point, Polar, Rect, Size};
+pub use gpu::{ShaderLanguage, SpringGpuUniform};
+#[cfg(feature = "nalgebra")]
+pub use integrations::DynMatrix;
+#[cfg(feature = "heapless")]
+pub use integrations::HeaplessVector;
+#[cfg(feature = "kurbo")]
+pub use integrations::PathAnimatable;
+#[cfg(feature = "smallvec")]
+pub use integrations::SmallVector;
+#[cfg(feature = "serde")]
+pub use integrations::{SerializeSpringAs, SpringRepresentation};
+pub use modular::{Modular, ModularScalar};
+pub use platform::{
+    AndroidSpringForce, CASpringParameters, ComposeSpringSpec, FlutterSpringDescription,
+    FramerSpring, TensionFriction,
+};
+pub use presets::SpringPresets;
+pub use quantizer::Quantizer;
+pub use rotation::{Quaternion, QuaternionSpring, Vector3};
+pub use rubber_band::{
+    RUBBER_BAND_COEFFICIENT, rubber_band, rubber_band_inverse, rubber_band_velocity,
+};
+pub use runge_kutta::RungeKutta4;
+pub use saturating_animatable::{AnimatableInteger, SaturatingAnimatable};
+pub use second_order_dynamics::SecondOrderDynamics;
+pub use simulation::{IntegrationMethod, SpringSimulation};
+pub use snap_points::{Detent, SnapPoints, SnapTarget};
 pub use spring::Spring;
+pub use spring_builder::{SpringBuilder, SpringParameter};
+pub use spring32::Spring32;
+pub use table::SpringTable;
+pub use transform::{DecomposedTransform2D, DecomposedTransform3D, Transform2D, Transform3D};
 pub use vector_arithmetic::VectorArithmetic;
@@ -0,0 +1,49 @@
+//! Floating-point helpers usable in const contexts.
+
+/// The square root of `x`, correctly rounded like [`f64::sqrt`].
+///
+/// `f64::sqrt` cannot be called in const contexts, so the root is computed
+/// from the bit representation with an integer square root.
+pub(crate) const fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+
+    const MANTISSA_BITS: u32 = 52;
+    const IMPLICIT_BIT: u64 = 1 << MANTISSA_BITS;
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> MANTISSA_BITS) & 0x7ff) as i32;
+    let mut mantissa = bits & (IMPLICIT_BIT - 1);
+    if exponent == 0 {
+        // Normalize subnormal values.
+        exponent = 1;
+        while mantissa < IMPLICIT_BIT {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+    } else {
+        mantissa |= IMPLICIT_BIT;
+    }
+    let mut exponent = exponent - 1023;
+    if exponent & 1 != 0 {
+        mantissa <<= 1;
+        exponent -= 1;
+    }
+
+    // `scaled` lies in [2^104, 2^106), so its root has exactly 53 bits.
+    let scaled = (mantissa as u128) << MANTISSA_BITS;
+    let mut root = scaled.isqrt();
+    // The root is never exactly halfway between two integers, so rounding
+    // to nearest only needs the remainder.
+    if scaled - root * root > root {
+        root += 1;
+    }
+
+    // A carry out of the mantissa correctly increments the exponent.
+    let biased_exponent = (exponent / 2 + 1023) as u64;
+    f64::from_bits((biased_exponent << MANTISSA_BITS) + (root as u64 - IMPLICIT_BIT))
+}
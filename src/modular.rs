@@ -0,0 +1,175 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating-point type that can be stored in a [`Modular`] value.
+///
+/// This trait is sealed and implemented for `f32` and `f64`.
+pub trait ModularScalar: private::Sealed + VectorArithmetic + Copy {
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    #[doc(hidden)]
+    fn from_f64(value: f64) -> Self;
+}
+
+impl ModularScalar for f32 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as Self
+    }
+}
+
+impl ModularScalar for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// A value on a circle of circumference `M`, such as a hue in degrees
+/// (`M = 360`), a clock hand (`M = 12` or `M = 60`) or an item index on a
+/// circular picker.
+///
+/// The arithmetic used by the spring must stay linear, so a `Modular` stores
+/// the unwrapped value and only wraps it when read with
+/// [`wrapped`](Self::wrapped). Subtraction does not wrap either, because the
+/// spring also subtracts velocities and the intermediate terms of its motion,
+/// which can exceed half the modulus. A spring moving a `Modular` towards a
+/// target therefore covers the unwrapped distance, which may be the longer
+/// way around. To animate along the shorter direction, pass the target
+/// through [`closest`](Self::closest) before handing it to the spring:
+///
+/// ```
+/// use respring::{Modular, Spring};
+///
+/// let mut hue = Modular::<f64, 360>::new(350.0);
+/// let mut velocity = Modular::new(0.0);
+/// let target = hue.closest(10.0);
+/// assert_eq!(target.0, 370.0);
+///
+/// Spring::smooth().update(&mut hue, &mut velocity, target, 1.0 / 60.0);
+/// assert!(hue.0 > 350.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modular<T, const M: u32>(pub T);
+
+impl<T: ModularScalar, const M: u32> Modular<T, M> {
+    /// The modulus of the value.
+    pub const MODULUS: u32 = M;
+
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value wrapped into the range `0..M`.
+    #[inline]
+    pub fn wrapped(&self) -> T {
+        T::from_f64(self.0.to_f64().rem_euclid(M as f64))
+    }
+
+    /// Returns the representation of `target` that is closest to this value.
+    ///
+    /// Animating towards the result crosses the modulus boundary whenever that
+    /// is the shorter way around. When both directions are equally long, the
+    /// positive direction is chosen.
+    pub fn closest(&self, target: T) -> Self {
+        let current = self.0.to_f64();
+        let difference = shortest_difference(current, target.to_f64(), M as f64);
+        Self(T::from_f64(current + difference))
+    }
+}
+
+/// Returns the difference from `from` to a representation of `to` on a circle
+/// of circumference `period`, taking the shorter way around, in the range
+/// `(-period / 2, period / 2]`.
+pub(crate) fn shortest_difference(from: f64, to: f64, period: f64) -> f64 {
+    let difference = (to - from).rem_euclid(period);
+    if difference > period / 2.0 {
+        difference - period
+    } else {
+        difference
+    }
+}
+
+impl<T: ModularScalar, const M: u32> Add for Modular<T, M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: ModularScalar, const M: u32> AddAssign for Modular<T, M> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T: ModularScalar, const M: u32> Sub for Modular<T, M> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T: ModularScalar, const M: u32> AdditiveArithmetic for Modular<T, M> {
+    const ZERO: Self = Self(T::ZERO);
+}
+
+impl<T: ModularScalar, const M: u32> VectorArithmetic for Modular<T, M> {
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.0.magnitude_squared()
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.0.scale_by(scalar);
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.0.dot(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_takes_the_shorter_way_around() {
+        let hue = Modular::<f64, 360>::new(350.0);
+        assert_eq!(hue.closest(10.0).0, 370.0);
+        assert_eq!(hue.closest(300.0).0, 300.0);
+        assert_eq!(Modular::<f32, 12>::new(-1.0).closest(5.0).0, 5.0);
+        // Equally long directions choose the positive one.
+        assert_eq!(Modular::<f64, 360>::new(0.0).closest(180.0).0, 180.0);
+        assert_eq!(Modular::<f64, 360>::new(720.0).closest(10.0).0, 730.0);
+    }
+}
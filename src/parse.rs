@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use crate::error::ParseSpringError;
+use crate::spring::Spring;
+use crate::spring_builder::SpringBuilder;
+
+/// The maximum number of arguments of any spring descriptor.
+const MAX_ARGUMENTS: usize = 4;
+
+impl Spring {
+    /// Parses a spring from the CSS `spring()` timing function syntax,
+    /// returning the spring and its initial velocity.
+    ///
+    /// The string has the form `spring(mass stiffness damping initialVelocity)`,
+    /// such as `spring(1 100 10 0)`. The name is matched without regard to
+    /// ASCII case, and arguments may also be separated by commas.
+    /// Overdamped combinations are allowed, as in CSS.
+    pub fn from_css_str(s: &str) -> Result<(Self, f64), ParseSpringError> {
+        let (name, arguments) = split_call(s)?;
+        if !name.eq_ignore_ascii_case("spring") {
+            return Err(ParseSpringError::InvalidSyntax);
+        }
+        let [mass, stiffness, damping, initial_velocity] = match parse_arguments(arguments)? {
+            (values, 4) => values,
+            _ => return Err(ParseSpringError::WrongArgumentCount),
+        };
+
+        let spring = Self::try_with_mass_stiffness_damping(mass, stiffness, damping, true)?;
+        if !initial_velocity.is_finite() {
+            return Err(ParseSpringError::InvalidNumber);
+        }
+        Ok((spring, initial_velocity))
+    }
+}
+
+impl FromStr for Spring {
+    type Err = ParseSpringError;
+
+    /// Parses a spring from a descriptor string.
+    ///
+    /// The following forms are accepted:
+    ///
+    /// * A preset name, `smooth`, `snappy`, `bouncy`, `interactive`, or
+    ///   `interpolating`, matched without regard to ASCII case.
+    /// * A tunable preset with a duration and optional extra bounce, such as
+    ///   `snappy(0.3)` or `bouncy(0.6, 0.1)`.
+    /// * Comma-separated parameters, such as `duration=0.5,bounce=0.2`. The
+    ///   keys are those of [`SpringBuilder`]: `duration`, `bounce`,
+    ///   `response`, `damping_ratio`, `mass`, `stiffness`, and `damping`.
+    /// * The CSS `spring()` syntax accepted by [`Spring::from_css_str`], with
+    ///   the initial velocity discarded.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.contains('=') {
+            return parse_parameters(s);
+        }
+
+        let (name, arguments) = split_call(s)?;
+        if name.eq_ignore_ascii_case("spring") {
+            return Self::from_css_str(s).map(|(spring, _)| spring);
+        }
+
+        let (values, count) = parse_arguments(arguments)?;
+        let interpolating = name.eq_ignore_ascii_case("interpolating");
+        if count > 2 || (interpolating && count > 0) {
+            return Err(ParseSpringError::WrongArgumentCount);
+        }
+        if interpolating {
+            return Ok(Self::INTERPOLATING);
+        }
+
+        let (default_duration, base_bounce) = if name.eq_ignore_ascii_case("smooth") {
+            (0.5, 0.0)
+        } else if name.eq_ignore_ascii_case("snappy") {
+            (0.5, 0.15)
+        } else if name.eq_ignore_ascii_case("bouncy") {
+            (0.5, 0.3)
+        } else if name.eq_ignore_ascii_case("interactive") {
+            (0.15, 0.14)
+        } else {
+            return Err(ParseSpringError::UnknownName);
+        };
+        let duration = if count > 0 {
+            values[0]
+        } else {
+            default_duration
+        };
+        let extra_bounce = if count > 1 { values[1] } else { 0.0 };
+        Ok(Self::try_with_duration_bounce(
+            duration,
+            base_bounce + extra_bounce,
+        )?)
+    }
+}
+
+/// Splits `name(arguments)` into its name and arguments. A bare `name` has
+/// no arguments.
+fn split_call(s: &str) -> Result<(&str, &str), ParseSpringError> {
+    let s = s.trim();
+    let Some((name, rest)) = s.split_once('(') else {
+        return Ok((s, ""));
+    };
+    let arguments = rest
+        .strip_suffix(')')
+        .ok_or(ParseSpringError::InvalidSyntax)?;
+    Ok((name.trim_end(), arguments))
+}
+
+/// Parses up to [`MAX_ARGUMENTS`] numbers separated by commas or whitespace,
+/// returning them with their count.
+fn parse_arguments(arguments: &str) -> Result<([f64; MAX_ARGUMENTS], usize), ParseSpringError> {
+    let mut values = [0.0; MAX_ARGUMENTS];
+    let mut count = 0;
+    for argument in arguments
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|argument| !argument.is_empty())
+    {
+        let value = values
+            .get_mut(count)
+            .ok_or(ParseSpringError::WrongArgumentCount)?;
+        *value = argument
+            .parse()
+            .map_err(|_| ParseSpringError::InvalidNumber)?;
+        count += 1;
+    }
+    Ok((values, count))
+}
+
+/// Parses `key=value` pairs separated by commas into a spring.
+fn parse_parameters(s: &str) -> Result<Spring, ParseSpringError> {
+    let mut builder = SpringBuilder::new();
+    for pair in s.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or(ParseSpringError::InvalidSyntax)?;
+        let value: f64 = value
+            .trim()
+            .parse()
+            .map_err(|_| ParseSpringError::InvalidNumber)?;
+        builder = match key.trim() {
+            "duration" => builder.duration(value),
+            "bounce" => builder.bounce(value),
+            "response" => builder.response(value),
+            "damping_ratio" | "dampingRatio" => builder.damping_ratio(value),
+            "mass" => builder.mass(value),
+            "stiffness" => builder.stiffness(value),
+            "damping" => builder.damping(value),
+            _ => return Err(ParseSpringError::UnknownName),
+        };
+    }
+    Ok(builder.try_build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_name_ignores_case() {
+        let expected = Spring::from_css_str("spring(1 100 10 0)").unwrap();
+        assert_eq!(
+            Spring::from_css_str("Spring(1 100 10 0)").unwrap(),
+            expected
+        );
+        assert_eq!(
+            "SPRING(1, 100, 10, 0)".parse::<Spring>().unwrap(),
+            expected.0
+        );
+    }
+}
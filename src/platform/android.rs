@@ -0,0 +1,126 @@
+use crate::spring::Spring;
+
+/// The parameters of an Android `SpringForce`.
+///
+/// Android springs have a unit mass and are described by their stiffness and
+/// damping ratio. The associated constants mirror those of `SpringForce`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AndroidSpringForce {
+    pub stiffness: f64,
+    pub damping_ratio: f64,
+}
+
+impl AndroidSpringForce {
+    /// `SpringForce.STIFFNESS_HIGH`.
+    pub const STIFFNESS_HIGH: f64 = 10_000.0;
+    /// `SpringForce.STIFFNESS_MEDIUM`.
+    pub const STIFFNESS_MEDIUM: f64 = 1_500.0;
+    /// `SpringForce.STIFFNESS_LOW`.
+    pub const STIFFNESS_LOW: f64 = 200.0;
+    /// `SpringForce.STIFFNESS_VERY_LOW`.
+    pub const STIFFNESS_VERY_LOW: f64 = 50.0;
+
+    /// `SpringForce.DAMPING_RATIO_HIGH_BOUNCY`.
+    pub const DAMPING_RATIO_HIGH_BOUNCY: f64 = 0.2;
+    /// `SpringForce.DAMPING_RATIO_MEDIUM_BOUNCY`.
+    pub const DAMPING_RATIO_MEDIUM_BOUNCY: f64 = 0.5;
+    /// `SpringForce.DAMPING_RATIO_LOW_BOUNCY`.
+    pub const DAMPING_RATIO_LOW_BOUNCY: f64 = 0.75;
+    /// `SpringForce.DAMPING_RATIO_NO_BOUNCY`.
+    pub const DAMPING_RATIO_NO_BOUNCY: f64 = 1.0;
+
+    /// The default `SpringForce`, with medium stiffness and medium bounce.
+    pub const DEFAULT: Self = Self::new(Self::STIFFNESS_MEDIUM, Self::DAMPING_RATIO_MEDIUM_BOUNCY);
+
+    #[inline]
+    pub const fn new(stiffness: f64, damping_ratio: f64) -> Self {
+        Self {
+            stiffness,
+            damping_ratio,
+        }
+    }
+}
+
+impl Default for AndroidSpringForce {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Spring {
+    /// Creates a spring from the parameters of an Android `SpringForce`.
+    ///
+    /// # Arguments
+    ///
+    /// * `stiffness` - The stiffness of a spring with a unit mass.
+    /// * `damping_ratio` - The amount of drag applied, as a fraction of the
+    ///   amount needed to produce critical damping. Values greater than 1 are
+    ///   overdamped.
+    pub fn from_android(stiffness: f64, damping_ratio: f64) -> Self {
+        let natural_frequency = stiffness.sqrt();
+        let oscillation = natural_frequency * (1.0 - damping_ratio * damping_ratio).abs().sqrt();
+        let angular_frequency = if damping_ratio > 1.0 {
+            -oscillation
+        } else {
+            oscillation
+        };
+        Self::new(angular_frequency, damping_ratio * natural_frequency, 1.0)
+    }
+
+    /// The parameters of the equivalent Android `SpringForce`.
+    ///
+    /// Android springs have a unit mass, so the stiffness is that of this
+    /// spring's motion normalized to a unit mass. A spring without stiffness
+    /// never oscillates, so it has the damping ratio
+    /// [`DAMPING_RATIO_NO_BOUNCY`](AndroidSpringForce::DAMPING_RATIO_NO_BOUNCY).
+    pub fn to_android(&self) -> AndroidSpringForce {
+        let stiffness = self.stiffness() / self.mass;
+        let damping_ratio = if stiffness > 0.0 {
+            self.decay_constant / stiffness.sqrt()
+        } else {
+            AndroidSpringForce::DAMPING_RATIO_NO_BOUNCY
+        };
+        AndroidSpringForce::new(stiffness, damping_ratio)
+    }
+}
+
+impl From<AndroidSpringForce> for Spring {
+    #[inline]
+    fn from(force: AndroidSpringForce) -> Self {
+        Self::from_android(force.stiffness, force.damping_ratio)
+    }
+}
+
+impl From<Spring> for AndroidSpringForce {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        spring.to_android()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_android() {
+        let spring = Spring::with_mass_stiffness_damping(2.0, 300.0, 20.0, false);
+        let force = spring.to_android();
+        assert!((force.stiffness - 150.0).abs() < 1e-9);
+        let round_trip = Spring::from(force);
+        assert!((round_trip.duration() - spring.duration()).abs() < 1e-9);
+        assert!((round_trip.bounce() - spring.bounce()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_stiffness_has_a_defined_damping_ratio() {
+        let force = Spring::new(0.0, 0.0, 1.0).to_android();
+        assert_eq!(force.stiffness, 0.0);
+        assert_eq!(
+            force.damping_ratio,
+            AndroidSpringForce::DAMPING_RATIO_NO_BOUNCY
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::spring::Spring;
+
+/// The parameters of a Jetpack Compose `SpringSpec`.
+///
+/// The arguments follow the order of Compose's `spring(dampingRatio,
+/// stiffness)`, and the associated constants mirror those of its `Spring`
+/// object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComposeSpringSpec {
+    pub damping_ratio: f64,
+    pub stiffness: f64,
+}
+
+impl ComposeSpringSpec {
+    /// `Spring.StiffnessHigh`.
+    pub const STIFFNESS_HIGH: f64 = 10_000.0;
+    /// `Spring.StiffnessMedium`.
+    pub const STIFFNESS_MEDIUM: f64 = 1_500.0;
+    /// `Spring.StiffnessMediumLow`.
+    pub const STIFFNESS_MEDIUM_LOW: f64 = 400.0;
+    /// `Spring.StiffnessLow`.
+    pub const STIFFNESS_LOW: f64 = 200.0;
+    /// `Spring.StiffnessVeryLow`.
+    pub const STIFFNESS_VERY_LOW: f64 = 50.0;
+
+    /// `Spring.DampingRatioHighBouncy`.
+    pub const DAMPING_RATIO_HIGH_BOUNCY: f64 = 0.2;
+    /// `Spring.DampingRatioMediumBouncy`.
+    pub const DAMPING_RATIO_MEDIUM_BOUNCY: f64 = 0.5;
+    /// `Spring.DampingRatioLowBouncy`.
+    pub const DAMPING_RATIO_LOW_BOUNCY: f64 = 0.75;
+    /// `Spring.DampingRatioNoBouncy`.
+    pub const DAMPING_RATIO_NO_BOUNCY: f64 = 1.0;
+
+    /// The defaults of Compose's `spring()`: no bounce and medium stiffness.
+    pub const DEFAULT: Self = Self::new(Self::DAMPING_RATIO_NO_BOUNCY, Self::STIFFNESS_MEDIUM);
+
+    #[inline]
+    pub const fn new(damping_ratio: f64, stiffness: f64) -> Self {
+        Self {
+            damping_ratio,
+            stiffness,
+        }
+    }
+}
+
+impl Default for ComposeSpringSpec {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Spring {
+    /// Creates a spring from the parameters of Compose's `spring()`.
+    ///
+    /// Compose springs share the unit-mass model of Android's `SpringForce`,
+    /// so this is equivalent to [`Spring::from_android`].
+    #[inline]
+    pub fn from_compose(damping_ratio: f64, stiffness: f64) -> Self {
+        Self::from_android(stiffness, damping_ratio)
+    }
+
+    /// The parameters of the equivalent Compose `SpringSpec`.
+    #[inline]
+    pub fn to_compose(&self) -> ComposeSpringSpec {
+        let force = self.to_android();
+        ComposeSpringSpec::new(force.damping_ratio, force.stiffness)
+    }
+}
+
+impl From<ComposeSpringSpec> for Spring {
+    #[inline]
+    fn from(spec: ComposeSpringSpec) -> Self {
+        Self::from_compose(spec.damping_ratio, spec.stiffness)
+    }
+}
+
+impl From<Spring> for ComposeSpringSpec {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        spring.to_compose()
+    }
+}
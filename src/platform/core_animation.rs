@@ -0,0 +1,70 @@
+use crate::spring::Spring;
+
+/// The properties of a `CASpringAnimation`.
+///
+/// Core Animation springs animate over a unit distance, so
+/// `initial_velocity` is expressed as a fraction of the distance from the
+/// `fromValue` to the `toValue` per second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CASpringParameters {
+    pub mass: f64,
+    pub stiffness: f64,
+    pub damping: f64,
+    pub initial_velocity: f64,
+    /// The time the spring takes to settle, suitable for the animation's
+    /// `duration`.
+    pub settling_duration: f64,
+}
+
+impl CASpringParameters {
+    /// Converts a velocity in the units of the animated value to the unitless
+    /// velocity `CASpringAnimation` expects.
+    ///
+    /// Returns 0 when `distance` is zero, since there is no travel to
+    /// normalize against.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocity` - The velocity of the animated value, per second.
+    /// * `distance` - The distance between the animation's `fromValue` and
+    ///   `toValue`.
+    #[inline]
+    pub fn normalized_velocity(velocity: f64, distance: f64) -> f64 {
+        if distance == 0.0 {
+            0.0
+        } else {
+            velocity / distance
+        }
+    }
+}
+
+impl Spring {
+    /// The properties of an equivalent `CASpringAnimation` that starts at
+    /// rest.
+    #[inline]
+    pub fn to_ca_spring(&self) -> CASpringParameters {
+        self.to_ca_spring_with_velocity(0.0, 1.0)
+    }
+
+    /// The properties of an equivalent `CASpringAnimation` that starts with
+    /// the given velocity.
+    ///
+    /// The settling duration accounts for the velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocity` - The initial velocity of the animated value, per second.
+    /// * `distance` - The distance between the animation's `fromValue` and
+    ///   `toValue`.
+    pub fn to_ca_spring_with_velocity(&self, velocity: f64, distance: f64) -> CASpringParameters {
+        let initial_velocity = CASpringParameters::normalized_velocity(velocity, distance);
+        CASpringParameters {
+            mass: self.mass,
+            stiffness: self.stiffness(),
+            damping: self.damping(),
+            initial_velocity,
+            settling_duration: self.settling_duration_with_velocity(1.0, initial_velocity, 0.001),
+        }
+    }
+}
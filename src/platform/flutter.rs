@@ -0,0 +1,70 @@
+use crate::spring::Spring;
+
+/// The parameters of a Flutter `SpringDescription`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlutterSpringDescription {
+    pub mass: f64,
+    pub stiffness: f64,
+    pub damping: f64,
+}
+
+impl FlutterSpringDescription {
+    #[inline]
+    pub const fn new(mass: f64, stiffness: f64, damping: f64) -> Self {
+        Self {
+            mass,
+            stiffness,
+            damping,
+        }
+    }
+
+    /// Creates a description from a damping ratio, like Flutter's
+    /// `SpringDescription.withDampingRatio`.
+    #[inline]
+    pub fn with_damping_ratio(mass: f64, stiffness: f64, ratio: f64) -> Self {
+        Self::new(mass, stiffness, ratio * 2.0 * (mass * stiffness).sqrt())
+    }
+}
+
+impl Spring {
+    /// Creates a spring from the parameters of a Flutter `SpringDescription`.
+    ///
+    /// Like Flutter's `SpringSimulation`, the spring is critically damped only
+    /// when `damping * damping - 4 * mass * stiffness` is exactly zero, and is
+    /// overdamped when it is positive.
+    #[inline]
+    pub fn from_flutter(mass: f64, stiffness: f64, damping: f64) -> Self {
+        super::spring_from_physical(mass, stiffness, damping)
+    }
+
+    /// The parameters of the equivalent Flutter `SpringDescription`.
+    ///
+    /// A critically damped spring is exported with its stiffness derived from
+    /// the damping, so that Flutter also treats it as critically damped. This
+    /// is exact for a unit mass, and as close as floating point allows
+    /// otherwise.
+    pub fn to_flutter(&self) -> FlutterSpringDescription {
+        let damping = self.damping();
+        let stiffness = if self.angular_frequency == 0.0 {
+            damping * damping / (4.0 * self.mass)
+        } else {
+            self.stiffness()
+        };
+        FlutterSpringDescription::new(self.mass, stiffness, damping)
+    }
+}
+
+impl From<FlutterSpringDescription> for Spring {
+    #[inline]
+    fn from(description: FlutterSpringDescription) -> Self {
+        Self::from_flutter(description.mass, description.stiffness, description.damping)
+    }
+}
+
+impl From<Spring> for FlutterSpringDescription {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        spring.to_flutter()
+    }
+}
@@ -0,0 +1,31 @@
+//! Conversions between [`Spring`](crate::Spring) and the spring
+//! parameterizations of other animation frameworks.
+
+mod android;
+mod compose;
+mod core_animation;
+mod flutter;
+mod web;
+
+pub use self::android::AndroidSpringForce;
+pub use self::compose::ComposeSpringSpec;
+pub use self::core_animation::CASpringParameters;
+pub use self::flutter::FlutterSpringDescription;
+pub use self::web::{FramerSpring, TensionFriction};
+
+use crate::spring::Spring;
+
+/// Creates a spring from physical parameters, deciding the kind of damping
+/// from the sign of the discriminant `damping^2 - 4 * mass * stiffness`.
+pub(crate) fn spring_from_physical(mass: f64, stiffness: f64, damping: f64) -> Spring {
+    let discriminant = damping * damping - 4.0 * mass * stiffness;
+    let oscillation = discriminant.abs().sqrt() / (2.0 * mass);
+    let angular_frequency = if discriminant == 0.0 {
+        0.0
+    } else if discriminant > 0.0 {
+        -oscillation
+    } else {
+        oscillation
+    };
+    Spring::new(angular_frequency, damping / (2.0 * mass), mass)
+}
@@ -0,0 +1,135 @@
+use crate::spring::Spring;
+
+/// A spring configuration in the tension and friction convention of
+/// react-spring and rebound.
+///
+/// Tension is the stiffness of the spring and friction its damping
+/// coefficient. The associated constants mirror react-spring's presets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TensionFriction {
+    pub tension: f64,
+    pub friction: f64,
+    pub mass: f64,
+}
+
+impl TensionFriction {
+    /// react-spring's `config.default`.
+    pub const DEFAULT: Self = Self::new(170.0, 26.0, 1.0);
+    /// react-spring's `config.gentle`.
+    pub const GENTLE: Self = Self::new(120.0, 14.0, 1.0);
+    /// react-spring's `config.wobbly`.
+    pub const WOBBLY: Self = Self::new(180.0, 12.0, 1.0);
+    /// react-spring's `config.stiff`.
+    pub const STIFF: Self = Self::new(210.0, 20.0, 1.0);
+    /// react-spring's `config.slow`.
+    pub const SLOW: Self = Self::new(280.0, 60.0, 1.0);
+    /// react-spring's `config.molasses`.
+    pub const MOLASSES: Self = Self::new(280.0, 120.0, 1.0);
+
+    #[inline]
+    pub const fn new(tension: f64, friction: f64, mass: f64) -> Self {
+        Self {
+            tension,
+            friction,
+            mass,
+        }
+    }
+}
+
+impl Default for TensionFriction {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A Framer Motion spring transition's `stiffness`, `damping`, and `mass`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FramerSpring {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+impl FramerSpring {
+    /// Framer Motion's default spring transition.
+    pub const DEFAULT: Self = Self::new(100.0, 10.0, 1.0);
+
+    #[inline]
+    pub const fn new(stiffness: f64, damping: f64, mass: f64) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+}
+
+impl Default for FramerSpring {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Spring {
+    /// Creates a spring from a react-spring or rebound configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `tension` - The spring stiffness coefficient.
+    /// * `friction` - The damping coefficient.
+    /// * `mass` - The mass of the object attached to the end of the spring.
+    #[inline]
+    pub fn from_tension_friction(tension: f64, friction: f64, mass: f64) -> Self {
+        super::spring_from_physical(mass, tension, friction)
+    }
+
+    /// The equivalent react-spring or rebound configuration.
+    #[inline]
+    pub fn to_tension_friction(&self) -> TensionFriction {
+        TensionFriction::new(self.stiffness(), self.damping(), self.mass)
+    }
+
+    /// Creates a spring from a Framer Motion spring transition.
+    #[inline]
+    pub fn from_framer(stiffness: f64, damping: f64, mass: f64) -> Self {
+        super::spring_from_physical(mass, stiffness, damping)
+    }
+
+    /// The equivalent Framer Motion spring transition.
+    #[inline]
+    pub fn to_framer(&self) -> FramerSpring {
+        FramerSpring::new(self.stiffness(), self.damping(), self.mass)
+    }
+}
+
+impl From<TensionFriction> for Spring {
+    #[inline]
+    fn from(config: TensionFriction) -> Self {
+        Self::from_tension_friction(config.tension, config.friction, config.mass)
+    }
+}
+
+impl From<Spring> for TensionFriction {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        spring.to_tension_friction()
+    }
+}
+
+impl From<FramerSpring> for Spring {
+    #[inline]
+    fn from(config: FramerSpring) -> Self {
+        Self::from_framer(config.stiffness, config.damping, config.mass)
+    }
+}
+
+impl From<Spring> for FramerSpring {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        spring.to_framer()
+    }
+}
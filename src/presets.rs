@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use crate::spring::Spring;
+
+/// A registry of named springs.
+///
+/// An app can register the springs of its motion design system once, such as
+/// `"sheet"` or `"toast"`, and resolve them by name wherever they are used.
+/// Names are iterated in sorted order.
+///
+/// With the `serde` feature, a registry serializes as a map from names to
+/// springs, and each spring can be given in any parameterization that
+/// [`Spring`] deserializes from.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SpringPresets {
+    springs: BTreeMap<String, Spring>,
+}
+
+impl SpringPresets {
+    /// Creates an empty registry.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            springs: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry containing the built-in presets: `smooth`, `snappy`,
+    /// `bouncy`, `interactive`, and `interpolating`.
+    pub fn with_builtins() -> Self {
+        [
+            ("smooth", Spring::SMOOTH),
+            ("snappy", Spring::SNAPPY),
+            ("bouncy", Spring::BOUNCY),
+            ("interactive", Spring::INTERACTIVE),
+            ("interpolating", Spring::INTERPOLATING),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Registers a spring under `name`, returning the spring it replaces.
+    #[inline]
+    pub fn insert(&mut self, name: impl Into<String>, spring: Spring) -> Option<Spring> {
+        self.springs.insert(name.into(), spring)
+    }
+
+    /// Returns the spring registered under `name`.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<Spring> {
+        self.springs.get(name).copied()
+    }
+
+    /// Returns the spring registered under `name`, or `fallback` if there is
+    /// none.
+    #[inline]
+    pub fn get_or(&self, name: &str, fallback: Spring) -> Spring {
+        self.get(name).unwrap_or(fallback)
+    }
+
+    /// Removes the spring registered under `name`, returning it.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<Spring> {
+        self.springs.remove(name)
+    }
+
+    /// Returns whether a spring is registered under `name`.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.springs.contains_key(name)
+    }
+
+    /// The number of registered springs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.springs.len()
+    }
+
+    /// Returns whether no springs are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.springs.is_empty()
+    }
+
+    /// Iterates over the registered springs in order of their names.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Spring)> + '_ {
+        self.springs
+            .iter()
+            .map(|(name, spring)| (name.as_str(), *spring))
+    }
+
+    /// Iterates over the registered names in sorted order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.springs.keys().map(String::as_str)
+    }
+}
+
+impl<N: Into<String>> FromIterator<(N, Spring)> for SpringPresets {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (N, Spring)>>(iter: I) -> Self {
+        let mut presets = Self::new();
+        presets.extend(iter);
+        presets
+    }
+}
+
+impl<N: Into<String>> Extend<(N, Spring)> for SpringPresets {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (N, Spring)>>(&mut self, iter: I) {
+        for (name, spring) in iter {
+            self.insert(name, spring);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::error::SpringError;
+
+/// Rounds the output of an animation to a device pixel grid, preventing the
+/// shimmering of values that oscillate across a pixel boundary at the end of a
+/// spring animation.
+///
+/// The quantizer remembers its last output, and only moves to a new grid
+/// position once the value has moved a full quantum away from it, so motion
+/// smaller than a quantum is suppressed. Values within half a quantum of the
+/// target snap to the target exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantizer {
+    /// The spacing of the grid, such as 1/3 of a point on a display with a
+    /// scale of 3.
+    pub quantum: f64,
+    output: Option<f64>,
+}
+
+impl Quantizer {
+    /// Creates a quantizer without validating the quantum, which
+    /// [`try_new`](Self::try_new) does. A quantum that is not positive and
+    /// finite makes every output NaN.
+    #[inline]
+    pub const fn new(quantum: f64) -> Self {
+        Self {
+            quantum,
+            output: None,
+        }
+    }
+
+    /// Creates a quantizer, validating the quantum.
+    ///
+    /// Returns an error if `quantum` is not finite or is not positive.
+    pub fn try_new(quantum: f64) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[quantum])?;
+        if quantum <= 0.0 {
+            return Err(SpringError::NonPositiveQuantum);
+        }
+        Ok(Self::new(quantum))
+    }
+
+    /// Creates a quantizer that rounds to the pixels of a display with the
+    /// given scale, in pixels per point, without validating the scale.
+    #[inline]
+    pub const fn with_display_scale(scale: f64) -> Self {
+        Self::new(1.0 / scale)
+    }
+
+    /// Creates a quantizer that rounds to the pixels of a display with the
+    /// given scale, validating the resulting quantum as
+    /// [`try_new`](Self::try_new) does.
+    #[inline]
+    pub fn try_with_display_scale(scale: f64) -> Result<Self, SpringError> {
+        Self::try_new(1.0 / scale)
+    }
+
+    /// The last output, or `None` if nothing has been quantized since the
+    /// quantizer was created or reset.
+    #[inline]
+    pub fn output(&self) -> Option<f64> {
+        self.output
+    }
+
+    /// Forgets the last output, so that the next value is rounded without
+    /// regard to it.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.output = None;
+    }
+
+    /// Returns `value` rounded to the nearest point on the grid, without
+    /// regard to the last output.
+    #[inline]
+    pub fn round(&self, value: f64) -> f64 {
+        (value / self.quantum).round() * self.quantum
+    }
+
+    /// Returns the quantized output for the current `value` of an animation
+    /// moving towards `target`.
+    pub fn quantize(&mut self, value: f64, target: f64) -> f64 {
+        let output = if (value - target).abs() <= self.quantum / 2.0 {
+            target
+        } else {
+            match self.output {
+                Some(output) if (value - output).abs() < self.quantum => output,
+                _ => self.round(value),
+            }
+        };
+        self.output = Some(output);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_new_rejects_degenerate_quanta() {
+        assert_eq!(
+            Quantizer::try_new(0.0),
+            Err(SpringError::NonPositiveQuantum)
+        );
+        assert_eq!(
+            Quantizer::try_new(-1.0),
+            Err(SpringError::NonPositiveQuantum)
+        );
+        assert_eq!(Quantizer::try_new(f64::NAN), Err(SpringError::NonFinite));
+        assert_eq!(
+            Quantizer::try_with_display_scale(0.0),
+            Err(SpringError::NonFinite)
+        );
+        assert_eq!(
+            Quantizer::try_with_display_scale(3.0),
+            Ok(Quantizer::new(1.0 / 3.0))
+        );
+    }
+}
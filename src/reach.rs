@@ -0,0 +1,68 @@
+use crate::spring::Spring;
+
+const BISECTION_ITERATIONS: u32 = 64;
+/// The number of times the search interval is doubled before a spring that
+/// does not oscillate is considered never to reach the fraction.
+const MAX_BRACKET_DOUBLINGS: u32 = 64;
+
+impl Spring {
+    /// Returns the first time at which the spring, starting at rest, has
+    /// covered `fraction` of the distance to its target.
+    ///
+    /// A `fraction` of 0 or less is reached immediately. Fractions beyond the
+    /// target can only be reached by springs that overshoot by at least that
+    /// much, as given by [`max_overshoot`](Self::max_overshoot). Returns
+    /// `None` if the fraction is never reached, which includes a fraction of
+    /// 1 or more for critically damped and overdamped springs, since they
+    /// only approach the target.
+    pub fn time_to_reach_fraction(&self, fraction: f64) -> Option<f64> {
+        if fraction.is_nan() {
+            return None;
+        }
+        if fraction <= 0.0 {
+            return Some(0.0);
+        }
+        let progress = |time: f64| self.value(1.0, 0.0, time);
+
+        // Starting at rest, the progress increases monotonically until the
+        // first peak, so the first time it reaches the fraction is bracketed
+        // by the start and that peak.
+        let mut high = match self.peak_time() {
+            Some(peak_time) => {
+                if fraction > 1.0 + self.max_overshoot() {
+                    return None;
+                }
+                peak_time
+            }
+            None => {
+                if fraction >= 1.0 {
+                    return None;
+                }
+                let mut high = 1.0 / self.natural_frequency();
+                let mut doublings = 0;
+                while progress(high) < fraction {
+                    if doublings == MAX_BRACKET_DOUBLINGS || !high.is_finite() {
+                        return None;
+                    }
+                    high *= 2.0;
+                    doublings += 1;
+                }
+                high
+            }
+        };
+        if !high.is_finite() {
+            return None;
+        }
+
+        let mut low = 0.0;
+        for _ in 0..BISECTION_ITERATIONS {
+            let middle = 0.5 * (low + high);
+            if progress(middle) >= fraction {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        Some(high)
+    }
+}
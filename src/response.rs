@@ -0,0 +1,40 @@
+use crate::spring::Spring;
+
+/// The responses of the spring viewed as a second-order low-pass filter with
+/// unity gain, `ω₀² / (s² + 2ζω₀s + ω₀²)`, in the terminology of control
+/// theory.
+impl Spring {
+    /// The step response, which is the value at `time` of a spring starting at
+    /// rest and moving towards a target of 1.
+    #[inline]
+    pub fn step_response(&self, time: f64) -> f64 {
+        self.value(1.0, 0.0, time)
+    }
+
+    /// The rate of change of the [step response](Self::step_response) at
+    /// `time`.
+    ///
+    /// This equals the [impulse response](Self::impulse_response).
+    #[inline]
+    pub fn step_response_velocity(&self, time: f64) -> f64 {
+        self.velocity(1.0, 0.0, time)
+    }
+
+    /// The impulse response, which is the output at `time` of the filter
+    /// after a unit impulse on its input at time 0.
+    ///
+    /// The output is at 0 and moving at `ω₀²` immediately after the impulse,
+    /// and then returns to 0. It is the derivative of the
+    /// [step response](Self::step_response).
+    #[inline]
+    pub fn impulse_response(&self, time: f64) -> f64 {
+        self.step_response_velocity(time)
+    }
+
+    /// The rate of change of the [impulse response](Self::impulse_response)
+    /// at `time`.
+    #[inline]
+    pub fn impulse_response_velocity(&self, time: f64) -> f64 {
+        self.acceleration(1.0, 0.0, time)
+    }
+}
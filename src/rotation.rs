@@ -0,0 +1,254 @@
+use std::ops::{Mul, Neg};
+
+use crate::spring::Spring;
+use crate::vector_arithmetic::componentwise_vector_arithmetic_impl;
+
+/// A three-dimensional vector, used for rotation vectors and angular
+/// velocities.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    /// The zero vector.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+    #[inline]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the dot-product of this vector with another vector.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The length of the vector.
+    #[inline]
+    pub fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+componentwise_vector_arithmetic_impl! {
+    Vector3 { x: f64, y: f64, z: f64 }
+}
+
+/// A quaternion representing an orientation in three-dimensional space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Default for Quaternion {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quaternion {
+    /// The quaternion that represents no rotation.
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    #[inline]
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a quaternion that rotates by `angle` radians around `axis`.
+    ///
+    /// The axis does not need to be normalized.
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let length = axis.length();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self::from_rotation_vector(axis * (angle / length))
+    }
+
+    /// Creates a quaternion from a rotation vector, whose direction is the
+    /// rotation axis and whose length is the rotation angle in radians.
+    ///
+    /// This is the exponential map from rotation vectors to orientations.
+    pub fn from_rotation_vector(rotation: Vector3) -> Self {
+        let angle = rotation.length();
+        if angle < 1e-12 {
+            // First-order expansion avoids dividing by a vanishing angle.
+            let half = rotation * 0.5;
+            return Self::new(half.x, half.y, half.z, 1.0).normalized();
+        }
+        let half_angle = angle / 2.0;
+        let axis = rotation * (half_angle.sin() / angle);
+        Self::new(axis.x, axis.y, axis.z, half_angle.cos())
+    }
+
+    /// Returns the rotation vector of this orientation: the rotation axis
+    /// scaled by the rotation angle in radians.
+    ///
+    /// This is the logarithmic map from orientations to rotation vectors. The
+    /// shorter of the two equivalent rotations is always returned, so the
+    /// angle never exceeds π.
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        let q = if self.w < 0.0 { -*self } else { *self };
+        let axis = Vector3::new(q.x, q.y, q.z);
+        let sin_half_angle = axis.length();
+        if sin_half_angle < 1e-12 {
+            return axis * 2.0;
+        }
+        let angle = 2.0 * sin_half_angle.atan2(q.w);
+        axis * (angle / sin_half_angle)
+    }
+
+    /// Returns the conjugate of this quaternion, which is the inverse rotation
+    /// for unit quaternions.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the dot-product of this quaternion with another quaternion.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// The length of the quaternion.
+    #[inline]
+    pub fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns this quaternion scaled to unit length.
+    pub fn normalized(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        let inverse = 1.0 / length;
+        Self::new(
+            self.x * inverse,
+            self.y * inverse,
+            self.z * inverse,
+            self.w * inverse,
+        )
+    }
+
+    /// Rotates the given vector by this quaternion.
+    pub fn rotate(&self, vector: Vector3) -> Vector3 {
+        let rotated = *self * Self::new(vector.x, vector.y, vector.z, 0.0) * self.conjugate();
+        Vector3::new(rotated.x, rotated.y, rotated.z)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    /// Composes two rotations, applying `rhs` first and then `self`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+/// A spring that animates orientations.
+///
+/// Rather than springing the four quaternion components independently, which
+/// leaves the unit sphere and can swing through the long way around, the
+/// remaining rotation is expressed as a rotation vector through the
+/// logarithmic map. The spring acts on that vector, and the travelled portion
+/// is applied back through the exponential map, so the orientation always
+/// follows the shortest arc and stays normalized.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuaternionSpring {
+    pub spring: Spring,
+}
+
+impl QuaternionSpring {
+    #[inline]
+    pub fn new(spring: Spring) -> Self {
+        Self { spring }
+    }
+
+    /// Calculates the orientation of the spring at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The orientation at time zero.
+    /// * `to` - The orientation the spring is moving towards.
+    /// * `angular_velocity` - The angular velocity at time zero, in radians
+    ///   per second, expressed as a rotation vector in world space.
+    /// * `time` - The time elapsed since the spring was at `from`.
+    pub fn value(
+        &self,
+        from: Quaternion,
+        to: Quaternion,
+        angular_velocity: Vector3,
+        time: f64,
+    ) -> Quaternion {
+        let remaining = (to * from.conjugate()).to_rotation_vector();
+        let travelled = self.spring.value(remaining, angular_velocity, time);
+        (Quaternion::from_rotation_vector(travelled) * from).normalized()
+    }
+
+    /// Calculates the angular velocity of the spring at a given time, in
+    /// radians per second.
+    pub fn velocity(
+        &self,
+        from: Quaternion,
+        to: Quaternion,
+        angular_velocity: Vector3,
+        time: f64,
+    ) -> Vector3 {
+        let remaining = (to * from.conjugate()).to_rotation_vector();
+        self.spring.velocity(remaining, angular_velocity, time)
+    }
+
+    /// Updates the current orientation and angular velocity of a spring.
+    ///
+    /// # Arguments
+    ///
+    /// * `orientation` - The current orientation of the spring.
+    /// * `angular_velocity` - The current angular velocity of the spring.
+    /// * `target` - The orientation that `orientation` is moving towards.
+    /// * `delta_time` - The amount of time that has passed since the spring
+    ///   was at the orientation specified by `orientation`.
+    pub fn update(
+        &self,
+        orientation: &mut Quaternion,
+        angular_velocity: &mut Vector3,
+        target: Quaternion,
+        delta_time: f64,
+    ) {
+        let remaining = (target * orientation.conjugate()).to_rotation_vector();
+        let travelled = self.spring.value(remaining, *angular_velocity, delta_time);
+        *angular_velocity = self
+            .spring
+            .velocity(remaining, *angular_velocity, delta_time);
+        *orientation = (Quaternion::from_rotation_vector(travelled) * *orientation).normalized();
+    }
+}
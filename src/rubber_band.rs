@@ -0,0 +1,59 @@
+//! The rubber-band curve of overscrolled iOS scroll views.
+
+/// The coefficient used by `UIScrollView`, which makes an offset of the full
+/// dimension appear as a little over a third of it.
+pub const RUBBER_BAND_COEFFICIENT: f64 = 0.55;
+
+/// Returns the displayed offset for an `offset` past the edge of content, as
+/// an overscrolled iOS scroll view shows it.
+///
+/// The displayed offset follows `(1 - 1 / (offset * coefficient / dimension + 1)) * dimension`,
+/// so it grows almost linearly for small offsets and approaches `dimension`
+/// for large ones. Negative offsets are mirrored.
+///
+/// # Arguments
+///
+/// * `offset` - How far past the edge the content has been dragged.
+/// * `dimension` - The size of the scroll view along the direction of the
+///   offset.
+/// * `coefficient` - How stiff the rubber band feels, usually
+///   [`RUBBER_BAND_COEFFICIENT`].
+pub fn rubber_band(offset: f64, dimension: f64, coefficient: f64) -> f64 {
+    let distance = offset.abs();
+    let displayed = dimension * coefficient * distance / (dimension + coefficient * distance);
+    displayed.copysign(offset)
+}
+
+/// Returns the offset past the edge of content that [`rubber_band`] displays
+/// as `displayed_offset`.
+///
+/// Displayed offsets of `dimension` or more in magnitude are never produced
+/// by the curve, and return an infinite offset.
+pub fn rubber_band_inverse(displayed_offset: f64, dimension: f64, coefficient: f64) -> f64 {
+    let distance = displayed_offset.abs();
+    let offset = if distance >= dimension {
+        f64::INFINITY
+    } else {
+        dimension * distance / (coefficient * (dimension - distance))
+    };
+    offset.copysign(displayed_offset)
+}
+
+/// Returns the velocity of the displayed offset when the content is dragged
+/// past its edge at `velocity`, which is the initial velocity to hand to the
+/// spring that snaps the content back on release.
+///
+/// # Arguments
+///
+/// * `offset` - How far past the edge the content has been dragged.
+/// * `velocity` - The velocity of the drag.
+/// * `dimension` - The size of the scroll view along the direction of the
+///   offset.
+/// * `coefficient` - How stiff the rubber band feels, usually
+///   [`RUBBER_BAND_COEFFICIENT`].
+pub fn rubber_band_velocity(offset: f64, velocity: f64, dimension: f64, coefficient: f64) -> f64 {
+    // The slope of the curve, which is `coefficient` at the edge and
+    // decreases from there.
+    let spread = dimension + coefficient * offset.abs();
+    velocity * dimension * dimension * coefficient / (spread * spread)
+}
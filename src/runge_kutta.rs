@@ -0,0 +1,83 @@
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A classical fourth-order Runge–Kutta integrator for a second-order motion
+/// model.
+///
+/// The model is a function `(time, value, velocity) -> acceleration` that
+/// gives the rate of change of the velocity for a state, while the rate of
+/// change of the value is the velocity itself. This covers extensions of the
+/// spring that have no closed-form solution, such as nonlinear forces,
+/// friction, and external forces.
+///
+/// The error of each step shrinks with the fourth power of its duration, so
+/// halving the step, or doubling the substeps passed to
+/// [`integrate`](Self::integrate), reduces it roughly sixteenfold.
+#[derive(Debug, Clone, Copy)]
+pub struct RungeKutta4<F> {
+    pub model: F,
+}
+
+impl<F> RungeKutta4<F> {
+    #[inline]
+    pub const fn new(model: F) -> Self {
+        Self { model }
+    }
+
+    /// Advances `value` and `velocity` from `time` by a single step of
+    /// `delta_time`.
+    pub fn step<V>(&self, value: &mut V, velocity: &mut V, time: f64, delta_time: f64)
+    where
+        V: VectorArithmetic,
+        F: Fn(f64, &V, &V) -> V,
+    {
+        let half_step = 0.5 * delta_time;
+        let (value_0, velocity_0) = (value.clone(), velocity.clone());
+
+        let acceleration_1 = (self.model)(time, &value_0, &velocity_0);
+
+        let value_2 = value_0.clone() + velocity_0.clone().scaled_by(half_step);
+        let velocity_2 = velocity_0.clone() + acceleration_1.clone().scaled_by(half_step);
+        let acceleration_2 = (self.model)(time + half_step, &value_2, &velocity_2);
+
+        let value_3 = value_0.clone() + velocity_2.clone().scaled_by(half_step);
+        let velocity_3 = velocity_0.clone() + acceleration_2.clone().scaled_by(half_step);
+        let acceleration_3 = (self.model)(time + half_step, &value_3, &velocity_3);
+
+        let value_4 = value_0.clone() + velocity_3.clone().scaled_by(delta_time);
+        let velocity_4 = velocity_0.clone() + acceleration_3.clone().scaled_by(delta_time);
+        let acceleration_4 = (self.model)(time + delta_time, &value_4, &velocity_4);
+
+        let sixth = delta_time / 6.0;
+        *value = value_0
+            + (velocity_0.clone() + (velocity_2 + velocity_3).scaled_by(2.0) + velocity_4)
+                .scaled_by(sixth);
+        *velocity = velocity_0
+            + (acceleration_1 + (acceleration_2 + acceleration_3).scaled_by(2.0) + acceleration_4)
+                .scaled_by(sixth);
+    }
+
+    /// Advances `value` and `velocity` from `time` by `duration`, split into
+    /// `steps` equal steps.
+    pub fn integrate<V>(
+        &self,
+        value: &mut V,
+        velocity: &mut V,
+        time: f64,
+        duration: f64,
+        steps: u32,
+    ) where
+        V: VectorArithmetic,
+        F: Fn(f64, &V, &V) -> V,
+    {
+        let steps = steps.max(1);
+        let delta_time = duration / steps as f64;
+        for index in 0..steps {
+            self.step(
+                value,
+                velocity,
+                time + index as f64 * delta_time,
+                delta_time,
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::additive_arithmetic::AdditiveArithmetic;
+use crate::vector_arithmetic::VectorArithmetic;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An integer type that can be animated through [`SaturatingAnimatable`].
+///
+/// This trait is sealed and implemented for all primitive integer types.
+pub trait AnimatableInteger: private::Sealed + Copy {
+    /// Converts the integer to a floating-point value.
+    fn to_f64(self) -> f64;
+
+    /// Rounds the floating-point value to the nearest integer, clamping it to
+    /// the range of the type.
+    fn from_f64_saturating(value: f64) -> Self;
+}
+
+macro_rules! animatable_integer_impl {
+    ($($t:ty)*) => ($(
+        impl private::Sealed for $t {}
+
+        impl AnimatableInteger for $t {
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline]
+            fn from_f64_saturating(value: f64) -> Self {
+                // Float-to-integer casts saturate at the bounds of the type.
+                value.round() as Self
+            }
+        }
+    )*)
+}
+
+animatable_integer_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+/// An integer quantity that is animated through an `f64` representation.
+///
+/// Integer arithmetic wraps or panics when a spring overshoots past the bounds
+/// of the type, such as an unsigned value dipping below zero. This wrapper
+/// keeps the animated state in an `f64`, so intermediate values and
+/// velocities can take any value, and only clamps when the integer is read
+/// back with [`get`](Self::get).
+pub struct SaturatingAnimatable<T> {
+    value: f64,
+    marker: PhantomData<T>,
+}
+
+impl<T: AnimatableInteger> SaturatingAnimatable<T> {
+    /// Creates an animatable value from the given integer.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self::from_f64(value.to_f64())
+    }
+
+    /// Creates an animatable value from an unrounded floating-point value.
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the value rounded to the nearest integer and clamped to the
+    /// range of `T`.
+    #[inline]
+    pub fn get(&self) -> T {
+        T::from_f64_saturating(self.value)
+    }
+
+    /// Returns the unrounded, unclamped value.
+    #[inline]
+    pub fn as_f64(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<T: AnimatableInteger> From<T> for SaturatingAnimatable<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> std::fmt::Debug for SaturatingAnimatable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SaturatingAnimatable")
+            .field(&self.value)
+            .finish()
+    }
+}
+
+impl<T> Clone for SaturatingAnimatable<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SaturatingAnimatable<T> {}
+
+impl<T> PartialEq for SaturatingAnimatable<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Add for SaturatingAnimatable<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value + rhs.value,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> AddAssign for SaturatingAnimatable<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<T> Sub for SaturatingAnimatable<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value - rhs.value,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> AdditiveArithmetic for SaturatingAnimatable<T> {
+    const ZERO: Self = Self {
+        value: 0.0,
+        marker: PhantomData,
+    };
+}
+
+impl<T> VectorArithmetic for SaturatingAnimatable<T> {
+    #[inline]
+    fn magnitude_squared(&self) -> f64 {
+        self.value * self.value
+    }
+
+    #[inline]
+    fn scale_by(&mut self, scalar: f64) {
+        self.value *= scalar;
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f64 {
+        self.value * other.value
+    }
+}
@@ -0,0 +1,106 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+/// A value following an input through second-order dynamics, parameterized by
+/// a frequency `f`, a damping ratio `ζ` and an initial response `r`.
+///
+/// The value `y` follows the input `x` according to
+/// `y + k₁y' + k₂y'' = x + k₃x'`, where `k₁ = ζ / (πf)`, `k₂ = 1 / (2πf)²` and
+/// `k₃ = rζ / (2πf)`. Without the `k₃x'` term this is an ordinary spring
+/// towards the input. That term makes the value react to the velocity of the
+/// input: a response of 0 starts moving slowly, 1 reacts immediately, values
+/// above 1 overshoot, and negative values anticipate by first moving away
+/// from the input. A response of 2 follows an input moving at constant
+/// velocity without lagging behind it.
+///
+/// Each [`update`](Self::update) solves the motion exactly for an input that
+/// moves linearly over the frame, so it is stable for any frame duration.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecondOrderDynamics<V> {
+    /// The spring with the frequency and damping ratio of the dynamics.
+    pub spring: Spring,
+    /// The initial response `r`.
+    pub response: f64,
+    pub value: V,
+    pub velocity: V,
+    previous_input: V,
+}
+
+impl<V> SecondOrderDynamics<V>
+where
+    V: VectorArithmetic,
+{
+    /// Creates dynamics at rest at `initial`, which is also the initial input.
+    ///
+    /// # Arguments
+    ///
+    /// * `frequency` - The natural frequency `f` of the motion, in hertz.
+    /// * `damping_ratio` - The damping ratio `ζ`, where 1 is critical damping.
+    /// * `response` - The initial response `r` to changes of the input.
+    /// * `initial` - The initial value and input.
+    pub fn new(frequency: f64, damping_ratio: f64, response: f64, initial: V) -> Self {
+        Self {
+            spring: Spring::with_response_damping_ratio(1.0 / frequency, damping_ratio),
+            response,
+            value: initial.clone(),
+            velocity: V::ZERO,
+            previous_input: initial,
+        }
+    }
+
+    /// The coefficient `k₃` of the velocity of the input.
+    #[inline]
+    fn input_velocity_coefficient(&self) -> f64 {
+        let natural_frequency = self.spring.natural_frequency();
+        self.response * self.spring.decay_constant / (natural_frequency * natural_frequency)
+    }
+
+    /// Advances the dynamics by `delta_time` towards `input`, estimating the
+    /// velocity of the input from its previous value, and returns the new
+    /// value.
+    pub fn update(&mut self, input: V, delta_time: f64) -> V {
+        if delta_time <= 0.0 {
+            return self.value.clone();
+        }
+        let input_velocity =
+            (input.clone() - self.previous_input.clone()).scaled_by(1.0 / delta_time);
+        self.update_with_input_velocity(input, input_velocity, delta_time)
+    }
+
+    /// Advances the dynamics by `delta_time` towards `input`, which moves at
+    /// `input_velocity`, and returns the new value.
+    ///
+    /// The input is taken to move linearly over the frame, at
+    /// `input_velocity`, until it reaches `input`.
+    pub fn update_with_input_velocity(
+        &mut self,
+        input: V,
+        input_velocity: V,
+        delta_time: f64,
+    ) -> V {
+        // The right-hand side `x + k₃x'` is a target that moves linearly over
+        // the frame, so the motion is a spring tracking it.
+        let target_start = input.clone() - input_velocity.clone().scaled_by(delta_time)
+            + input_velocity
+                .clone()
+                .scaled_by(self.input_velocity_coefficient());
+        let target_position = target_start - self.value.clone();
+        let value = self.spring.value_tracking(
+            target_position.clone(),
+            input_velocity.clone(),
+            self.velocity.clone(),
+            delta_time,
+        );
+        let velocity = self.spring.velocity_tracking(
+            target_position,
+            input_velocity,
+            self.velocity.clone(),
+            delta_time,
+        );
+        self.value += value;
+        self.velocity = velocity;
+        self.previous_input = input;
+        self.value.clone()
+    }
+}
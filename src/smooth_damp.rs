@@ -0,0 +1,49 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl Spring {
+    /// Gradually moves `current` towards `target` over about `smooth_time`
+    /// seconds, like Unity's `Mathf.SmoothDamp`, returning the new value.
+    ///
+    /// The motion is that of a critically damped spring with a decay constant
+    /// of `2 / smooth_time`, computed exactly rather than with Unity's
+    /// polynomial approximation, so it never overshoots from rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current value.
+    /// * `target` - The value to move towards.
+    /// * `velocity` - The current velocity, which is updated in place and
+    ///   should be passed back in on the next call.
+    /// * `smooth_time` - Approximately the time it takes to reach the target.
+    /// * `max_speed` - The maximum speed, which limits how far the target may
+    ///   be from `current` to `max_speed * smooth_time`. Pass
+    ///   [`f64::INFINITY`] for no limit.
+    /// * `delta_time` - The time since the last call.
+    pub fn smooth_damp<V>(
+        current: V,
+        target: V,
+        velocity: &mut V,
+        smooth_time: f64,
+        max_speed: f64,
+        delta_time: f64,
+    ) -> V
+    where
+        V: VectorArithmetic,
+    {
+        // Unity clamps the smooth time to avoid a division by zero.
+        let smooth_time = smooth_time.max(1e-4);
+        let spring = Self::new(0.0, 2.0 / smooth_time, 1.0);
+
+        let mut change = target - current.clone();
+        let max_change = max_speed * smooth_time;
+        let distance_squared = change.magnitude_squared();
+        if distance_squared > max_change * max_change {
+            change.scale_by(max_change / distance_squared.sqrt());
+        }
+
+        let mut value = current.clone();
+        spring.update(&mut value, velocity, current + change, delta_time);
+        value
+    }
+}
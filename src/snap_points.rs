@@ -0,0 +1,124 @@
+use crate::decay::{Decay, project};
+use crate::spring::Spring;
+
+/// A resting position that a [`SnapPoints`] resolver can snap to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Detent {
+    /// The value at the detent.
+    pub value: f64,
+    /// How strongly the detent attracts the projected value. Distances to a
+    /// detent are divided by its strength, so a detent with a strength of 2
+    /// wins over one twice as close with a strength of 1. Defaults to 1.
+    pub strength: f64,
+}
+
+impl Detent {
+    /// Creates a detent at `value` with a strength of 1.
+    #[inline]
+    pub const fn new(value: f64) -> Self {
+        Self::with_strength(value, 1.0)
+    }
+
+    #[inline]
+    pub const fn with_strength(value: f64, strength: f64) -> Self {
+        Self { value, strength }
+    }
+}
+
+/// The detent chosen by [`SnapPoints::resolve`] and how to animate to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapTarget {
+    /// The index of the chosen detent.
+    pub index: usize,
+    /// The value of the chosen detent.
+    pub target: f64,
+    /// The spring to animate to the detent with.
+    pub spring: Spring,
+    /// The initial velocity to pass to the spring along with the amount of
+    /// change `target - value`.
+    pub initial_velocity: f64,
+}
+
+/// Chooses where a flung value should come to rest among a set of detents,
+/// such as the detents of a sheet or the pages of a carousel.
+///
+/// The resolver projects where the value would naturally stop with
+/// [`project`], and then picks the detent closest to that point, weighted by
+/// each detent's strength.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapPoints {
+    detents: Vec<Detent>,
+    /// The spring used to animate to the chosen detent.
+    pub spring: Spring,
+    /// The deceleration rate used to project the value, which defaults to
+    /// that of [`Decay::NORMAL`].
+    pub deceleration_rate: f64,
+}
+
+impl SnapPoints {
+    /// Creates a resolver with detents of strength 1 at `values`, animating
+    /// with the [default spring](Spring::default).
+    pub fn new(values: impl IntoIterator<Item = f64>) -> Self {
+        values.into_iter().map(Detent::new).collect()
+    }
+
+    /// Sets the spring used to animate to the chosen detent.
+    #[inline]
+    pub fn with_spring(mut self, spring: Spring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    /// Sets the deceleration rate used to project the value.
+    #[inline]
+    pub fn with_deceleration_rate(mut self, deceleration_rate: f64) -> Self {
+        self.deceleration_rate = deceleration_rate;
+        self
+    }
+
+    /// Adds a detent.
+    #[inline]
+    pub fn push(&mut self, detent: Detent) {
+        self.detents.push(detent);
+    }
+
+    /// The detents, in the order they were added.
+    #[inline]
+    pub fn detents(&self) -> &[Detent] {
+        &self.detents
+    }
+
+    /// Chooses the detent for a value released at `velocity`, or returns
+    /// `None` if there are no detents.
+    pub fn resolve(&self, value: f64, velocity: f64) -> Option<SnapTarget> {
+        let projected = project(value, velocity, self.deceleration_rate);
+        let (index, detent) = self.detents.iter().enumerate().min_by(|(_, a), (_, b)| {
+            let distance = |detent: &Detent| (detent.value - projected).abs() / detent.strength;
+            distance(a).total_cmp(&distance(b))
+        })?;
+        Some(SnapTarget {
+            index,
+            target: detent.value,
+            spring: self.spring,
+            initial_velocity: velocity,
+        })
+    }
+}
+
+impl FromIterator<Detent> for SnapPoints {
+    fn from_iter<I: IntoIterator<Item = Detent>>(iter: I) -> Self {
+        Self {
+            detents: iter.into_iter().collect(),
+            spring: Spring::default(),
+            deceleration_rate: Decay::NORMAL.deceleration_rate,
+        }
+    }
+}
+
+impl Extend<Detent> for SnapPoints {
+    fn extend<I: IntoIterator<Item = Detent>>(&mut self, iter: I) {
+        self.detents.extend(iter);
+    }
+}
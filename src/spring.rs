@@ -1,7 +1,17 @@
+use std::fmt;
+
+use crate::default_spring;
+use crate::error::SpringError;
+use crate::math;
 use crate::vector_arithmetic::VectorArithmetic;
 
 /// A representation of a spring's motion.
-#[derive(Debug, Clone, Copy)]
+///
+/// The layout is `#[repr(C)]`, so the parameters can be copied directly into
+/// GPU uniform buffers or shared memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[repr(C)]
 pub struct Spring {
     pub angular_frequency: f64,
     pub decay_constant: f64,
@@ -9,21 +19,56 @@ pub struct Spring {
 }
 
 impl Spring {
-    pub fn new(angular_frequency: f64, decay_constant: f64, mass: f64) -> Self {
+    pub const fn new(angular_frequency: f64, decay_constant: f64, mass: f64) -> Self {
         Self {
             angular_frequency,
             decay_constant,
             mass,
         }
     }
+
+    /// Creates a spring from its raw parameters, validating them.
+    ///
+    /// Returns an error if any parameter is not finite or `mass` is not
+    /// positive.
+    pub fn try_new(
+        angular_frequency: f64,
+        decay_constant: f64,
+        mass: f64,
+    ) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[angular_frequency, decay_constant, mass])?;
+        if mass <= 0.0 {
+            return Err(SpringError::NonPositiveMass);
+        }
+        Ok(Self::new(angular_frequency, decay_constant, mass))
+    }
+
+    /// Returns whether every parameter of this spring is within `tolerance`
+    /// of the corresponding parameter of `other`.
+    ///
+    /// Springs created through different parameterizations rarely compare
+    /// exactly equal, because each conversion rounds differently.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        (self.angular_frequency - other.angular_frequency).abs() <= tolerance
+            && (self.decay_constant - other.decay_constant).abs() <= tolerance
+            && (self.mass - other.mass).abs() <= tolerance
+    }
 }
 
 impl Spring {
     #[inline]
-    pub fn with_duration(duration: f64) -> Self {
+    pub const fn with_duration(duration: f64) -> Self {
         Self::with_duration_bounce(duration, 0.0)
     }
 
+    /// Creates a spring with the specified duration and no bounce, validating
+    /// the duration.
+    #[inline]
+    pub fn try_with_duration(duration: f64) -> Result<Self, SpringError> {
+        Self::try_with_duration_bounce(duration, 0.0)
+    }
+
     /// Creates a spring with the specified duration and bounce.
     ///
     /// # Arguments
@@ -37,7 +82,7 @@ impl Spring {
     ///   increasing amounts of bounciness up to a maximum of 1.0
     ///   (corresponding to undamped oscillation), and negative values
     ///   indicate overdamped springs with a minimum value of -1.0.
-    pub fn with_duration_bounce(duration: f64, bounce: f64) -> Self {
+    pub const fn with_duration_bounce(duration: f64, bounce: f64) -> Self {
         let mut angular_velocity_factor: f64 = -std::f64::consts::TAU;
         let mut damping_ratio: f64 = f64::INFINITY;
 
@@ -63,8 +108,9 @@ impl Spring {
         }
 
         // Calculate final spring parameters
-        let angular_frequency =
-            (1.0 - damping_ratio * damping_ratio).abs().sqrt() * angular_velocity_factor / duration;
+        let angular_frequency = math::sqrt((1.0 - damping_ratio * damping_ratio).abs())
+            * angular_velocity_factor
+            / duration;
         let decay_constant = damping_ratio * std::f64::consts::TAU / duration;
         let mass = 1.0;
 
@@ -75,6 +121,18 @@ impl Spring {
         }
     }
 
+    /// Creates a spring with the specified duration and bounce, validating
+    /// them.
+    ///
+    /// Returns an error if either argument is not finite, `duration` is not
+    /// positive, or `bounce` does not satisfy `-1.0 < bounce <= 1.0`.
+    pub fn try_with_duration_bounce(duration: f64, bounce: f64) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[duration, bounce])?;
+        SpringError::ensure_duration(duration)?;
+        SpringError::ensure_bounce(bounce)?;
+        Ok(Self::with_duration_bounce(duration, bounce))
+    }
+
     /// The perceptual duration, which defines the pace of the spring.
     #[inline]
     pub fn duration(&self) -> f64 {
@@ -119,29 +177,28 @@ impl Spring {
     ///   should be allowed when appropriate based on the other inputs, and a
     ///   value of false specifies that such cases should instead be treated as
     ///   critically damped.
-    pub fn with_mass_stiffness_damping(
+    pub const fn with_mass_stiffness_damping(
         mass: f64,
         stiffness: f64,
         damping: f64,
         allow_over_damping: bool,
     ) -> Self {
-        let natural_frequency = (stiffness / mass).sqrt();
+        let natural_frequency = math::sqrt(stiffness / mass);
         let damping_ratio = damping / (2.0 * mass);
 
-        let (angular_frequency, decay_constant) =
-            if damping_ratio > natural_frequency && !allow_over_damping {
-                (0.0, natural_frequency)
+        let (angular_frequency, decay_constant) = if damping_ratio > natural_frequency
+            && !allow_over_damping
+        {
+            (0.0, natural_frequency)
+        } else {
+            let oscillation = math::sqrt((stiffness / mass - damping_ratio * damping_ratio).abs());
+            let angular_freq = if damping_ratio > natural_frequency {
+                -oscillation
             } else {
-                let oscillation = (stiffness / mass - damping_ratio * damping_ratio)
-                    .abs()
-                    .sqrt();
-                let angular_freq = if damping_ratio > natural_frequency {
-                    -oscillation
-                } else {
-                    oscillation
-                };
-                (angular_freq, damping_ratio)
+                oscillation
             };
+            (angular_freq, damping_ratio)
+        };
 
         Self {
             angular_frequency,
@@ -150,15 +207,51 @@ impl Spring {
         }
     }
 
+    /// Creates a spring with the specified mass, stiffness, and damping,
+    /// validating them.
+    ///
+    /// Returns an error if any argument is not finite, `mass` or `stiffness`
+    /// is not positive, or `damping` is negative.
+    pub fn try_with_mass_stiffness_damping(
+        mass: f64,
+        stiffness: f64,
+        damping: f64,
+        allow_over_damping: bool,
+    ) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[mass, stiffness, damping])?;
+        if mass <= 0.0 {
+            return Err(SpringError::NonPositiveMass);
+        }
+        if stiffness <= 0.0 {
+            return Err(SpringError::NonPositiveStiffness);
+        }
+        if damping < 0.0 {
+            return Err(SpringError::NegativeDamping);
+        }
+        Ok(Self::with_mass_stiffness_damping(
+            mass,
+            stiffness,
+            damping,
+            allow_over_damping,
+        ))
+    }
+
     /// The spring stiffness coefficient.
     ///
     /// Increasing the stiffness reduces the number of oscillations and will
     /// reduce the settling duration. Decreasing the stiffness increases the the
     /// number of oscillations and will increase the settling duration.
+    ///
+    /// For overdamped springs this differs from SwiftUI's `Spring.stiffness`
+    /// and from the Swift sources of this package, which add the squares of
+    /// the angular frequency and decay constant and so overstate the
+    /// stiffness. This returns the stiffness the spring was created with.
     #[inline]
     pub fn stiffness(&self) -> f64 {
+        // The angular frequency is negative for overdamped springs, whose
+        // natural frequency squared is the difference of the squares.
         self.mass
-            * (self.angular_frequency * self.angular_frequency
+            * (self.angular_frequency * self.angular_frequency.abs()
                 + self.decay_constant * self.decay_constant)
     }
 
@@ -173,6 +266,53 @@ impl Spring {
     pub fn damping(&self) -> f64 {
         self.decay_constant * 2.0 * self.mass
     }
+
+    /// Creates a spring with a unit mass and the specified stiffness and
+    /// bounce.
+    ///
+    /// # Arguments
+    ///
+    /// * `stiffness` - The spring coefficient.
+    /// * `bounce` - How bouncy the spring should be, as in
+    ///   [`with_duration_bounce`](Self::with_duration_bounce).
+    pub const fn with_stiffness_bounce(stiffness: f64, bounce: f64) -> Self {
+        let natural_frequency = math::sqrt(stiffness);
+        let damping_ratio = if bounce < 0.0 {
+            1.0 / (bounce + 1.0)
+        } else if bounce <= 1.0 {
+            1.0 - bounce
+        } else {
+            0.0
+        };
+
+        let oscillation =
+            natural_frequency * math::sqrt((1.0 - damping_ratio * damping_ratio).abs());
+        let angular_frequency = if damping_ratio > 1.0 {
+            -oscillation
+        } else {
+            oscillation
+        };
+
+        Self {
+            angular_frequency,
+            decay_constant: damping_ratio * natural_frequency,
+            mass: 1.0,
+        }
+    }
+
+    /// Creates a spring with a unit mass and the specified stiffness and
+    /// bounce, validating them.
+    ///
+    /// Returns an error if either argument is not finite, `stiffness` is not
+    /// positive, or `bounce` does not satisfy `-1.0 < bounce <= 1.0`.
+    pub fn try_with_stiffness_bounce(stiffness: f64, bounce: f64) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[stiffness, bounce])?;
+        if stiffness <= 0.0 {
+            return Err(SpringError::NonPositiveStiffness);
+        }
+        SpringError::ensure_bounce(bounce)?;
+        Ok(Self::with_stiffness_bounce(stiffness, bounce))
+    }
 }
 
 impl Spring {
@@ -184,7 +324,7 @@ impl Spring {
     ///   duration in seconds.
     /// * `damping_ratio` - Defines the amount of drag applied as a fraction the
     ///   amount needed to produce critical damping.
-    pub fn with_response_damping_ratio(response: f64, damping_ratio: f64) -> Self {
+    pub const fn with_response_damping_ratio(response: f64, damping_ratio: f64) -> Self {
         // Calculate angular frequency and decay based on whether system is overdamped.
         let is_overdamped = damping_ratio > 1.0;
         let tau_factor = if is_overdamped {
@@ -196,7 +336,7 @@ impl Spring {
         let damping_offset = (1.0 - ratio_squared).abs();
 
         // Calculate final spring parameters.
-        let frequency_component = damping_offset.sqrt();
+        let frequency_component = math::sqrt(damping_offset);
         let angular_frequency = (tau_factor * frequency_component) / response;
         let decay_constant = (std::f64::consts::TAU * damping_ratio) / response;
 
@@ -207,6 +347,23 @@ impl Spring {
         }
     }
 
+    /// Creates a spring with the specified response and damping ratio,
+    /// validating them.
+    ///
+    /// Returns an error if either argument is not finite, `response` is not
+    /// positive, or `damping_ratio` is negative.
+    pub fn try_with_response_damping_ratio(
+        response: f64,
+        damping_ratio: f64,
+    ) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[response, damping_ratio])?;
+        SpringError::ensure_duration(response)?;
+        if damping_ratio < 0.0 {
+            return Err(SpringError::BounceOutOfRange);
+        }
+        Ok(Self::with_response_damping_ratio(response, damping_ratio))
+    }
+
     /// The stiffness of the spring, defined as an approximate duration in seconds.
     #[inline]
     pub fn response(&self) -> f64 {
@@ -227,6 +384,167 @@ impl Spring {
     }
 }
 
+impl Spring {
+    /// The undamped natural frequency of the spring, in radians per second.
+    ///
+    /// This is the frequency the spring would oscillate at without damping.
+    #[inline]
+    pub fn natural_frequency(&self) -> f64 {
+        (self.decay_constant * self.decay_constant
+            + self.angular_frequency * self.angular_frequency.abs())
+        .sqrt()
+    }
+
+    /// The frequency the spring actually oscillates at, in radians per second.
+    ///
+    /// This is 0 for critically damped and overdamped springs, which do not
+    /// oscillate.
+    #[inline]
+    pub fn damped_natural_frequency(&self) -> f64 {
+        self.angular_frequency.max(0.0)
+    }
+
+    /// The quality factor of the spring, the ratio of its natural frequency
+    /// to its bandwidth.
+    ///
+    /// Higher values ring for longer. The quality factor is 0.5 for a
+    /// critically damped spring, and infinite for an undamped one.
+    #[inline]
+    pub fn quality_factor(&self) -> f64 {
+        self.natural_frequency() / (2.0 * self.decay_constant)
+    }
+
+    /// The duration of one oscillation, in seconds.
+    ///
+    /// This is infinite for critically damped and overdamped springs, which do
+    /// not oscillate.
+    #[inline]
+    pub fn period(&self) -> f64 {
+        std::f64::consts::TAU / self.damped_natural_frequency()
+    }
+
+    /// How far the first peak goes past the target when starting at rest, as
+    /// a fraction of the distance travelled.
+    ///
+    /// This is 0 for critically damped and overdamped springs, which never
+    /// overshoot from rest, and 1 for an undamped spring.
+    #[inline]
+    pub fn max_overshoot(&self) -> f64 {
+        match self.peak_time() {
+            Some(peak_time) => (-self.decay_constant * peak_time).exp(),
+            None => 0.0,
+        }
+    }
+
+    /// The time of the first peak when starting at rest, in seconds.
+    ///
+    /// Returns `None` for critically damped and overdamped springs, which
+    /// approach the target without peaking.
+    #[inline]
+    pub fn peak_time(&self) -> Option<f64> {
+        (self.angular_frequency > 0.0).then(|| std::f64::consts::PI / self.angular_frequency)
+    }
+}
+
+impl Spring {
+    /// Creates a spring with the specified half-life and damping ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `half_life` - The time it takes for the envelope of the motion to
+    ///   halve, in seconds.
+    /// * `damping_ratio` - The amount of drag applied as a fraction of the
+    ///   amount needed to produce critical damping. Must be positive, since an
+    ///   undamped spring never decays.
+    pub const fn with_half_life(half_life: f64, damping_ratio: f64) -> Self {
+        let decay_rate = std::f64::consts::LN_2 / half_life;
+        let oscillation_factor = math::sqrt((1.0 - damping_ratio * damping_ratio).abs());
+
+        if damping_ratio > 1.0 {
+            // The slowest of the two decaying modes dominates the envelope.
+            let natural_frequency = decay_rate / (damping_ratio - oscillation_factor);
+            Self {
+                angular_frequency: -natural_frequency * oscillation_factor,
+                decay_constant: damping_ratio * natural_frequency,
+                mass: 1.0,
+            }
+        } else {
+            let natural_frequency = decay_rate / damping_ratio;
+            Self {
+                angular_frequency: natural_frequency * oscillation_factor,
+                decay_constant: decay_rate,
+                mass: 1.0,
+            }
+        }
+    }
+
+    /// Creates a spring with the specified half-life and damping ratio,
+    /// validating them.
+    ///
+    /// Returns an error if either argument is not finite, `half_life` is not
+    /// positive, or `damping_ratio` is not positive.
+    pub fn try_with_half_life(half_life: f64, damping_ratio: f64) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[half_life, damping_ratio])?;
+        SpringError::ensure_duration(half_life)?;
+        if damping_ratio <= 0.0 {
+            return Err(SpringError::BounceOutOfRange);
+        }
+        Ok(Self::with_half_life(half_life, damping_ratio))
+    }
+
+    /// The time it takes for the envelope of the motion to halve, in seconds.
+    ///
+    /// For overdamped springs, this is the half-life of the slower of the two
+    /// decaying modes. It is infinite for an undamped spring.
+    #[inline]
+    pub fn half_life(&self) -> f64 {
+        let decay_rate = if self.angular_frequency < 0.0 {
+            self.decay_constant + self.angular_frequency
+        } else {
+            self.decay_constant
+        };
+        std::f64::consts::LN_2 / decay_rate
+    }
+}
+
+impl Spring {
+    /// Interpolates between two springs by their duration and bounce.
+    ///
+    /// Interpolating the perceptual parameters, rather than the raw ones,
+    /// changes the pace and bounciness of the motion evenly as `t` moves from
+    /// 0 to 1. The mass is interpolated linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The spring returned when `t` is 0.
+    /// * `b` - The spring returned when `t` is 1.
+    /// * `t` - The interpolation fraction. Values outside `0.0...1.0`
+    ///   extrapolate.
+    pub fn mix(a: Self, b: Self, t: f64) -> Self {
+        let lerp = |from: f64, to: f64| from + (to - from) * t;
+        let mut spring = Self::with_duration_bounce(
+            lerp(a.duration(), b.duration()),
+            lerp(a.bounce(), b.bounce()),
+        );
+        spring.mass = lerp(a.mass, b.mass);
+        spring
+    }
+
+    /// Returns a spring whose motion plays `factor` times as fast, with the
+    /// same bounce.
+    ///
+    /// A factor of 2 halves the duration, and a factor of 0.5 doubles it.
+    /// The factor must be positive.
+    #[inline]
+    pub const fn scaled_speed(&self, factor: f64) -> Self {
+        Self {
+            angular_frequency: self.angular_frequency * factor,
+            decay_constant: self.decay_constant * factor,
+            mass: self.mass,
+        }
+    }
+}
+
 impl Spring {
     /// Creates a spring with the specified duration and damping ratio.
     ///
@@ -373,6 +691,84 @@ impl Spring {
             mass: 1.0,
         }
     }
+
+    /// Creates a spring with the specified settling duration and damping
+    /// ratio, validating them.
+    ///
+    /// Returns an error if any argument is not finite, `settling_duration` or
+    /// `epsilon` is not positive, or `damping_ratio` does not satisfy
+    /// `0.0 < damping_ratio <= 1.0`.
+    pub fn try_with_settling_duration_damping_ratio(
+        settling_duration: f64,
+        damping_ratio: f64,
+        epsilon: f64,
+    ) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[settling_duration, damping_ratio, epsilon])?;
+        SpringError::ensure_duration(settling_duration)?;
+        if damping_ratio <= 0.0 || damping_ratio > 1.0 {
+            return Err(SpringError::BounceOutOfRange);
+        }
+        if epsilon <= 0.0 {
+            return Err(SpringError::NonPositiveEpsilon);
+        }
+        Ok(Self::with_settling_duration_damping_ratio(
+            settling_duration,
+            damping_ratio,
+            epsilon,
+        ))
+    }
+
+    /// Creates a spring with the specified settling duration whose first peak
+    /// overshoots the target by the specified fraction.
+    ///
+    /// # Arguments
+    ///
+    /// * `settling_duration` - The approximate time it will take for the spring to come to rest.
+    /// * `overshoot_fraction` - How far the first peak goes past the target, as a fraction of the
+    ///   distance travelled. For example, 0.05 allows a 5% overshoot. A value of 0 produces a
+    ///   critically damped spring.
+    pub fn with_settling_duration_overshoot(
+        settling_duration: f64,
+        overshoot_fraction: f64,
+    ) -> Self {
+        Self::with_settling_duration_damping_ratio(
+            settling_duration,
+            Self::damping_ratio_for_overshoot(overshoot_fraction),
+            0.001,
+        )
+    }
+
+    /// Creates a spring with the specified settling duration and first-peak
+    /// overshoot, validating them.
+    ///
+    /// Returns an error if either argument is not finite, `settling_duration`
+    /// is not positive, or `overshoot_fraction` does not satisfy
+    /// `0.0 <= overshoot_fraction < 1.0`.
+    pub fn try_with_settling_duration_overshoot(
+        settling_duration: f64,
+        overshoot_fraction: f64,
+    ) -> Result<Self, SpringError> {
+        SpringError::ensure_finite(&[settling_duration, overshoot_fraction])?;
+        if !(0.0..1.0).contains(&overshoot_fraction) {
+            return Err(SpringError::BounceOutOfRange);
+        }
+        Self::try_with_settling_duration_damping_ratio(
+            settling_duration,
+            Self::damping_ratio_for_overshoot(overshoot_fraction),
+            0.001,
+        )
+    }
+
+    /// The damping ratio of a spring whose first peak overshoots by
+    /// `overshoot_fraction`, from `overshoot = exp(-ζπ / sqrt(1 - ζ²))`.
+    fn damping_ratio_for_overshoot(overshoot_fraction: f64) -> f64 {
+        if overshoot_fraction <= 0.0 {
+            return 1.0;
+        }
+        let log_overshoot = overshoot_fraction.min(1.0).ln();
+        -log_overshoot
+            / (std::f64::consts::PI * std::f64::consts::PI + log_overshoot * log_overshoot).sqrt()
+    }
 }
 
 impl Spring {
@@ -402,45 +798,63 @@ impl Spring {
             return f64::INFINITY;
         }
 
-        if self.angular_frequency <= 0.0 {
-            let mut best_time = -1.0;
-            let mut time: f64 = 0.0;
-            let mut best_distance: f64 = f64::INFINITY;
-
-            for _ in 0..1024 {
-                let current_value = self.value(target.clone(), initial_velocity.clone(), time);
-                let diff = current_value - target.clone();
-                let distance = diff.magnitude_squared().sqrt();
-                if distance.is_nan() || distance.is_infinite() {
-                    break;
-                }
-
-                if best_distance >= epsilon {
-                    if distance < best_distance {
-                        best_time = time;
-                        best_distance = distance;
-                    }
-                } else if distance >= epsilon {
-                    best_distance = f64::INFINITY;
-                } else if time - best_time > 1.0 {
-                    return best_time;
-                }
+        if self.angular_frequency < 0.0 {
+            // The remaining distance is `S e^(s1 t) + F e^(s2 t)`, which is
+            // bounded by an envelope that decreases monotonically.
+            let slow_rate = -self.decay_constant - self.angular_frequency;
+            let fast_rate = self.angular_frequency - self.decay_constant;
+            let slow = (initial_velocity + target.clone().scaled_by(fast_rate))
+                .scaled_by(-1.0 / (slow_rate - fast_rate));
+            let fast = target - slow.clone();
+            let (slow, fast) = (slow.magnitude(), fast.magnitude());
+
+            // The slow mode alone decays no faster than the envelope.
+            let upper_bound = ((slow + fast) / epsilon).ln() / -slow_rate;
+            return settling_time(
+                |time| slow * (slow_rate * time).exp() + fast * (fast_rate * time).exp(),
+                0.0,
+                upper_bound,
+                epsilon,
+            );
+        }
 
-                time += 0.1;
-            }
+        if self.angular_frequency == 0.0 {
+            // The remaining distance is `e^(-dt) (τ + K t)`, whose envelope
+            // peaks at `1/d - |τ|/|K|` and decreases afterwards.
+            let decay = self.decay_constant;
+            let constant = target.magnitude();
+            let linear = (target.scaled_by(decay) - initial_velocity).magnitude();
+            let peak_time = if linear > 0.0 {
+                (1.0 / decay - constant / linear).max(0.0)
+            } else {
+                0.0
+            };
 
-            return 0.0;
+            // `t e^(-dt/2)` is at most `2 / (d e)`, so the envelope is at most
+            // `(|τ| + 2|K| / (d e)) e^(-dt/2)`.
+            let scale = constant + 2.0 * linear / (decay * std::f64::consts::E);
+            let upper_bound = (2.0 * (scale / epsilon).ln() / decay).max(peak_time);
+            return settling_time(
+                |time| (-decay * time).exp() * (constant + linear * time),
+                peak_time,
+                upper_bound,
+                epsilon,
+            );
         }
 
         let magnitude = (target.clone().scaled_by(self.decay_constant) - initial_velocity)
-            .magnitude_squared()
-            .sqrt()
-            + target.magnitude_squared().sqrt();
+            .magnitude()
+            + target.magnitude();
         let settling_time = -(epsilon / magnitude).ln() / self.decay_constant;
         settling_time.max(0.0)
     }
 
     /// Calculates the value of the spring at a given time given a target amount of change.
+    ///
+    /// Negative times extrapolate backwards along the same motion, giving the
+    /// value the spring would have had before time 0 to arrive at the start
+    /// with `initial_velocity`. Use [`value_clamped`](Self::value_clamped) to
+    /// hold the start value instead.
     pub fn value<V>(&self, target: V, initial_velocity: V, time: f64) -> V
     where
         V: VectorArithmetic,
@@ -476,6 +890,13 @@ impl Spring {
     }
 
     /// Calculates the velocity of the spring at a given time given a target amount of change.
+    ///
+    /// Negative times extrapolate backwards along the same motion, as in
+    /// [`value`](Self::value).
+    ///
+    /// For overdamped springs this is the derivative of the value, which
+    /// differs from SwiftUI's `Spring.velocity` and from the Swift sources of
+    /// this package, whose results are off by `target`.
     pub fn velocity<V>(&self, target: V, initial_velocity: V, time: f64) -> V
     where
         V: VectorArithmetic,
@@ -508,8 +929,7 @@ impl Spring {
 
             let scale_factor = ((self.decay_constant - self.angular_frequency) * term1
                 + negative_freq_minus_damping * term2)
-                / (self.angular_frequency * 2.0)
-                + 1.0;
+                / (self.angular_frequency * 2.0);
             let velocity_factor = (term1 - term2) / (self.angular_frequency * 2.0);
 
             target.clone().scaled_by(scale_factor)
@@ -524,6 +944,121 @@ impl Spring {
         }
     }
 
+    /// Calculates the value of the spring at a given time given a target amount
+    /// of change, treating negative times as time 0.
+    ///
+    /// Before the start of the motion, the value stays at its start value of
+    /// zero, which suits scrubbing a timeline to before an animation begins.
+    #[inline]
+    pub fn value_clamped<V>(&self, target: V, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        self.value(target, initial_velocity, time.max(0.0))
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target
+    /// amount of change, treating negative times as time 0.
+    ///
+    /// Before the start of the motion, the velocity is `initial_velocity`, as
+    /// at time 0.
+    #[inline]
+    pub fn velocity_clamped<V>(&self, target: V, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        self.velocity(target, initial_velocity, time.max(0.0))
+    }
+
+    /// The decay of the motion at a given time, `e^(-λt)`, where `λ` is the
+    /// decay constant.
+    ///
+    /// This is 1 at the start and bounds the relative amplitude of an
+    /// underdamped spring's oscillation. Overdamped springs consist of a
+    /// faster and a slower mode around this rate.
+    #[inline]
+    pub fn envelope(&self, time: f64) -> f64 {
+        (-self.decay_constant * time).exp()
+    }
+
+    /// Decomposes the motion of an underdamped spring into an amplitude `A`
+    /// and phase `φ`, such that
+    /// `value(target, initial_velocity, t) = target + A·e^(-λt)·cos(ωt + φ)`.
+    ///
+    /// Here `λ` is the decay constant and `ω` the angular frequency. Returns
+    /// `None` for critically damped and overdamped springs, which do not
+    /// oscillate.
+    pub fn amplitude_phase(&self, target: f64, initial_velocity: f64) -> Option<(f64, f64)> {
+        if self.angular_frequency <= 0.0 {
+            return None;
+        }
+        let cos = -target;
+        let sin = (target * self.decay_constant - initial_velocity) / self.angular_frequency;
+        Some((cos.hypot(sin), sin.atan2(cos)))
+    }
+
+    /// Calculates the acceleration of the spring at a given time given a target amount of change.
+    ///
+    /// This value is in units of the vector type per second squared.
+    pub fn acceleration<V>(&self, target: V, initial_velocity: V, time: f64) -> V
+    where
+        V: VectorArithmetic,
+    {
+        // The motion satisfies `x'' = ω0² (target - x) - 2 ζ ω0 x'`, where
+        // `ζ ω0` is the decay constant.
+        let natural_frequency_squared = self.decay_constant * self.decay_constant
+            + self.angular_frequency * self.angular_frequency.abs();
+        let value = self.value(target.clone(), initial_velocity.clone(), time);
+        let velocity = self.velocity(target.clone(), initial_velocity, time);
+        (target - value).scaled_by(natural_frequency_squared)
+            - velocity.scaled_by(2.0 * self.decay_constant)
+    }
+
+    /// Calculates the value and velocity of a spring moving from `from` to
+    /// `to` at a given time.
+    ///
+    /// Unlike [`value`](Self::value), which works in amounts of change, this
+    /// takes and returns absolute values.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The value of the spring at time 0.
+    /// * `to` - The value the spring is moving towards.
+    /// * `initial_velocity` - The velocity of the spring at time 0.
+    /// * `time` - The time since the spring was at `from`.
+    pub fn evaluate<V>(&self, from: V, to: V, initial_velocity: V, time: f64) -> (V, V)
+    where
+        V: VectorArithmetic,
+    {
+        let delta = to - from.clone();
+        let velocity = self.velocity(delta.clone(), initial_velocity.clone(), time);
+        let value = from + self.value(delta, initial_velocity, time);
+        (value, velocity)
+    }
+
+    /// Returns the target amount of change and initial velocity that continue
+    /// the motion of a spring at `current_value` moving at `current_velocity`
+    /// towards `new_target`.
+    ///
+    /// Passing the results to [`value`](Self::value) and
+    /// [`velocity`](Self::velocity), with time measured from the moment of
+    /// retargeting, continues the motion without a jump in either the value
+    /// or the velocity. The motion only depends on the current state, so the
+    /// previous target is not needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_value` - The value of the spring when the target changes.
+    /// * `current_velocity` - The velocity of the spring when the target
+    ///   changes.
+    /// * `new_target` - The value the spring should now move towards.
+    pub fn retarget<V>(&self, current_value: V, current_velocity: V, new_target: V) -> (V, V)
+    where
+        V: VectorArithmetic,
+    {
+        (new_target - current_value, current_velocity)
+    }
+
     /// Updates the current value and velocity of a spring.
     ///
     /// # Arguments
@@ -544,9 +1079,67 @@ impl Spring {
         *value += delta_value;
     }
 
+    /// Updates the current value and velocity of a spring, snapping them to
+    /// the target and zero once the spring has settled, and returns whether
+    /// it has.
+    ///
+    /// A spring only approaches its target without ever reaching it, so this
+    /// gives animations a definite end. The spring is settled when
+    /// [`is_settled`](Self::is_settled) holds for `epsilon` after the update.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The current value of the spring.
+    /// * `velocity` - The current velocity of the spring.
+    /// * `target` - The target that `value` is moving towards.
+    /// * `delta_time` - The amount of time that has passed since the spring was
+    ///   at the position specified by `value`.
+    /// * `epsilon` - The distance from the target within which the spring is
+    ///   considered settled.
+    pub fn update_with_epsilon<V>(
+        &self,
+        value: &mut V,
+        velocity: &mut V,
+        target: V,
+        delta_time: f64,
+        epsilon: f64,
+    ) -> bool
+    where
+        V: VectorArithmetic,
+    {
+        self.update(value, velocity, target.clone(), delta_time);
+        let settled = self.is_settled(value, velocity, &target, epsilon);
+        if settled {
+            *value = target;
+            *velocity = V::ZERO;
+        }
+        settled
+    }
+
+    /// Returns whether a spring at `value` moving at `velocity` has come to
+    /// rest at `target`.
+    ///
+    /// The spring is settled when it is within `epsilon` of the target and its
+    /// velocity is within `epsilon` times the
+    /// [`natural_frequency`](Self::natural_frequency). Scaling the velocity by
+    /// the natural frequency measures it as the distance the spring would
+    /// still travel, so the same `epsilon` applies to both.
+    pub fn is_settled<V>(&self, value: &V, velocity: &V, target: &V, epsilon: f64) -> bool
+    where
+        V: VectorArithmetic,
+    {
+        let speed_epsilon = epsilon * self.natural_frequency();
+        value.distance_to(target) <= epsilon
+            && velocity.magnitude_squared() <= speed_epsilon * speed_epsilon
+    }
+
     /// Calculates the force upon the spring given a current position, target, and velocity amount of change.
     ///
     /// This value is in units of the vector type per second squared.
+    ///
+    /// The spring force uses [`stiffness`](Self::stiffness), so for
+    /// overdamped springs it differs from SwiftUI's `Spring.force` and from
+    /// the Swift sources of this package in the same way.
     pub fn force<V>(&self, target: V, position: V, velocity: V) -> V
     where
         V: VectorArithmetic,
@@ -555,20 +1148,69 @@ impl Spring {
             .clone()
             .scaled_by((-self.decay_constant * 2.0) * self.mass);
         let delta = target - position;
-        let spring_force = delta.scaled_by(
-            (self.angular_frequency * self.angular_frequency
-                + self.decay_constant * self.decay_constant)
-                * self.mass,
-        );
+        let spring_force = delta.scaled_by(self.stiffness());
         spring_force + damping_force
     }
 }
 
+impl Default for Spring {
+    /// Returns the spring set with
+    /// [`set_default_spring`](crate::set_default_spring), which is the
+    /// [`smooth`](Self::smooth) spring unless changed.
+    #[inline]
+    fn default() -> Self {
+        default_spring::default_spring()
+    }
+}
+
+impl fmt::Display for Spring {
+    /// Formats the spring by its duration, bounce, and kind of damping, such as
+    /// `spring(duration: 0.50s, bounce: 0.15, underdamped)`.
+    ///
+    /// The precision defaults to two decimal places.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let damping = if self.decay_constant == 0.0 {
+            "undamped"
+        } else if self.angular_frequency > 0.0 {
+            "underdamped"
+        } else if self.angular_frequency < 0.0 {
+            "overdamped"
+        } else {
+            "critically damped"
+        };
+        write!(
+            f,
+            "spring(duration: {:.precision$}s, bounce: {:.precision$}, {damping})",
+            self.duration(),
+            self.bounce(),
+        )
+    }
+}
+
 impl Spring {
+    /// A smooth spring with a duration of 0.5 seconds and no bounce.
+    pub const SMOOTH: Self = Self::with_duration_bounce(0.5, 0.0);
+
+    /// A snappy spring with a duration of 0.5 seconds and a bounce of 0.15.
+    pub const SNAPPY: Self = Self::with_duration_bounce(0.5, 0.15);
+
+    /// A bouncy spring with a duration of 0.5 seconds and a bounce of 0.3.
+    pub const BOUNCY: Self = Self::with_duration_bounce(0.5, 0.3);
+
+    /// A quick spring with a duration of 0.15 seconds and a bounce of 0.14,
+    /// suited to tracking direct manipulation.
+    pub const INTERACTIVE: Self = Self::with_duration_bounce(0.15, 0.14);
+
+    /// The spring of SwiftUI's static `interpolatingSpring`, with a duration
+    /// of 0.5 seconds and no bounce, which moves the same as
+    /// [`SMOOTH`](Self::SMOOTH).
+    pub const INTERPOLATING: Self = Self::with_duration_bounce(0.5, 0.0);
+
     /// A smooth spring with a predefined duration and no bounce.
     #[inline]
-    pub fn smooth() -> Self {
-        Self::with_duration_bounce(0.5, 0.0)
+    pub const fn smooth() -> Self {
+        Self::SMOOTH
     }
 
     /// A smooth spring with a predefined duration and no bounce that can be
@@ -583,15 +1225,21 @@ impl Spring {
     /// * `extra_bounce` - How much additional bounce should be added to the base
     ///   bounce of 0.
     #[inline]
-    pub fn smooth_with_duration(duration: f64, extra_bounce: f64) -> Self {
+    pub const fn smooth_with_duration(duration: f64, extra_bounce: f64) -> Self {
         Self::with_duration_bounce(duration, extra_bounce)
     }
 
+    /// A tunable smooth spring, validating the resulting duration and bounce.
+    #[inline]
+    pub fn try_smooth_with_duration(duration: f64, extra_bounce: f64) -> Result<Self, SpringError> {
+        Self::try_with_duration_bounce(duration, extra_bounce)
+    }
+
     /// A spring with a predefined duration and small amount of bounce that
     /// feels more snappy.
     #[inline]
-    pub fn snappy() -> Self {
-        Self::with_duration_bounce(0.5, 0.15)
+    pub const fn snappy() -> Self {
+        Self::SNAPPY
     }
 
     /// A spring with a predefined duration and small amount of bounce that
@@ -606,14 +1254,20 @@ impl Spring {
     /// * `extra_bounce` - How much additional bounciness should be added to the
     ///   base bounce of 0.15.
     #[inline]
-    pub fn snappy_with_duration(duration: f64, extra_bounce: f64) -> Self {
+    pub const fn snappy_with_duration(duration: f64, extra_bounce: f64) -> Self {
         Self::with_duration_bounce(duration, 0.15 + extra_bounce)
     }
 
+    /// A tunable snappy spring, validating the resulting duration and bounce.
+    #[inline]
+    pub fn try_snappy_with_duration(duration: f64, extra_bounce: f64) -> Result<Self, SpringError> {
+        Self::try_with_duration_bounce(duration, 0.15 + extra_bounce)
+    }
+
     /// A spring with a predefined duration and higher amount of bounce.
     #[inline]
-    pub fn bouncy() -> Self {
-        Self::with_duration_bounce(0.5, 0.3)
+    pub const fn bouncy() -> Self {
+        Self::BOUNCY
     }
 
     /// A spring with a predefined duration and higher amount of bounce that
@@ -628,7 +1282,133 @@ impl Spring {
     /// * `extra_bounce` - How much additional bounce should be added to the base
     ///   bounce of 0.3.
     #[inline]
-    pub fn bouncy_with_duration(duration: f64, extra_bounce: f64) -> Self {
+    pub const fn bouncy_with_duration(duration: f64, extra_bounce: f64) -> Self {
         Self::with_duration_bounce(duration, 0.3 + extra_bounce)
     }
+
+    /// A tunable bouncy spring, validating the resulting duration and bounce.
+    #[inline]
+    pub fn try_bouncy_with_duration(duration: f64, extra_bounce: f64) -> Result<Self, SpringError> {
+        Self::try_with_duration_bounce(duration, 0.3 + extra_bounce)
+    }
+
+    /// A spring with a short duration and a small amount of bounce, suited to
+    /// tracking direct manipulation such as drags.
+    ///
+    /// Matches SwiftUI's `interactiveSpring()`.
+    #[inline]
+    pub const fn interactive() -> Self {
+        Self::INTERACTIVE
+    }
+
+    /// A spring with a short duration and a small amount of bounce that can be
+    /// tuned.
+    ///
+    /// Matches SwiftUI's `interactiveSpring(duration:extraBounce:)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The perceptual duration, which defines the pace of the
+    ///   spring. This is approximately equal to the settling duration, but
+    ///   for very bouncy springs, will be the duration of the period of
+    ///   oscillation for the spring.
+    /// * `extra_bounce` - How much additional bounce should be added to the base
+    ///   bounce of 0.14.
+    #[inline]
+    pub const fn interactive_with_duration(duration: f64, extra_bounce: f64) -> Self {
+        Self::with_duration_bounce(duration, 0.14 + extra_bounce)
+    }
+
+    /// A tunable interactive spring, validating the resulting duration and
+    /// bounce.
+    #[inline]
+    pub fn try_interactive_with_duration(
+        duration: f64,
+        extra_bounce: f64,
+    ) -> Result<Self, SpringError> {
+        Self::try_with_duration_bounce(duration, 0.14 + extra_bounce)
+    }
+
+    /// The spring of SwiftUI's static `interpolatingSpring`, with a duration
+    /// of 0.5 seconds and no bounce.
+    #[inline]
+    pub const fn interpolating() -> Self {
+        Self::INTERPOLATING
+    }
+}
+
+/// The number of bisection steps used to solve for the settling duration.
+const SETTLING_BISECTION_ITERATIONS: u32 = 64;
+
+/// Solves for the time at which `envelope`, which decreases monotonically
+/// over `[low, high]`, falls to `epsilon`. Returns 0 if the envelope is already
+/// below `epsilon` at `low`.
+fn settling_time(envelope: impl Fn(f64) -> f64, low: f64, high: f64, epsilon: f64) -> f64 {
+    if envelope(low) <= epsilon {
+        return 0.0;
+    }
+    if !high.is_finite() {
+        return high;
+    }
+    let (mut low, mut high) = (low, high);
+    for _ in 0..SETTLING_BISECTION_ITERATIONS {
+        let middle = 0.5 * (low + high);
+        if envelope(middle) > epsilon {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    high
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overdamped_stiffness_round_trips() {
+        let spring = Spring::with_mass_stiffness_damping(2.0, 100.0, 60.0, true);
+        assert!((spring.stiffness() - 100.0).abs() < 1e-9);
+        assert!((spring.damping() - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interpolating_has_the_swiftui_duration_and_bounce() {
+        let spring = Spring::interpolating();
+        assert!((spring.duration() - 0.5).abs() < 1e-9);
+        assert!(spring.bounce().abs() < 1e-9);
+        assert_eq!("interpolating".parse::<Spring>().unwrap(), spring);
+    }
+
+    #[test]
+    fn overdamped_force_uses_the_stiffness() {
+        let spring = Spring::with_mass_stiffness_damping(2.0, 100.0, 60.0, true);
+        let force = spring.force(1.0_f64, 0.0, 0.0);
+        assert!((force - 100.0).abs() < 1e-9, "{force}");
+        let force = spring.force(0.0_f64, 0.0, 1.0);
+        assert!((force + 60.0).abs() < 1e-9, "{force}");
+    }
+
+    #[test]
+    fn velocity_is_the_derivative_of_value() {
+        let springs = [
+            Spring::bouncy(),
+            Spring::smooth(),
+            Spring::with_mass_stiffness_damping(2.0, 100.0, 60.0, true),
+        ];
+        let step = 1e-6;
+        for spring in springs {
+            for time in [0.0, 0.05, 0.2, 0.5] {
+                let before = spring.value(1.0, 2.0, time);
+                let after = spring.value(1.0, 2.0, time + step);
+                let expected = (after - before) / step;
+                let velocity = spring.velocity(1.0, 2.0, time + step / 2.0);
+                assert!(
+                    (velocity - expected).abs() < 1e-4,
+                    "{spring:?} at {time}s: {velocity} != {expected}"
+                );
+            }
+        }
+    }
 }
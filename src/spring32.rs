@@ -0,0 +1,535 @@
+use std::f32::consts::{PI, TAU};
+use std::fmt;
+
+use crate::spring::Spring;
+
+/// A single-precision representation of a spring's motion.
+///
+/// `Spring32` mirrors the core of [`Spring`] with every computation performed
+/// in `f32`, which avoids double-precision trigonometry on targets where it is
+/// measurably slower, such as mobile devices and WebAssembly. It
+/// animates `f32` values directly.
+///
+/// Parameterizations that are only available on [`Spring`] can be converted
+/// with `Spring32::from`.
+///
+/// Like [`Spring`], the layout is `#[repr(C)]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Spring32 {
+    pub angular_frequency: f32,
+    pub decay_constant: f32,
+    pub mass: f32,
+}
+
+impl Spring32 {
+    pub const fn new(angular_frequency: f32, decay_constant: f32, mass: f32) -> Self {
+        Self {
+            angular_frequency,
+            decay_constant,
+            mass,
+        }
+    }
+
+    /// Returns whether every parameter of this spring is within `tolerance`
+    /// of the corresponding parameter of `other`.
+    ///
+    /// Springs created through different parameterizations rarely compare
+    /// exactly equal, because each conversion rounds differently.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        (self.angular_frequency - other.angular_frequency).abs() <= tolerance
+            && (self.decay_constant - other.decay_constant).abs() <= tolerance
+            && (self.mass - other.mass).abs() <= tolerance
+    }
+}
+
+impl fmt::Display for Spring32 {
+    /// Formats the spring like [`Spring`]'s `Display` implementation.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Spring::from(*self), f)
+    }
+}
+
+impl From<Spring> for Spring32 {
+    #[inline]
+    fn from(spring: Spring) -> Self {
+        Self::new(
+            spring.angular_frequency as f32,
+            spring.decay_constant as f32,
+            spring.mass as f32,
+        )
+    }
+}
+
+impl From<Spring32> for Spring {
+    #[inline]
+    fn from(spring: Spring32) -> Self {
+        Self::new(
+            spring.angular_frequency as f64,
+            spring.decay_constant as f64,
+            spring.mass as f64,
+        )
+    }
+}
+
+impl Spring32 {
+    #[inline]
+    pub fn with_duration(duration: f32) -> Self {
+        Self::with_duration_bounce(duration, 0.0)
+    }
+
+    /// Creates a spring with the specified duration and bounce.
+    ///
+    /// See [`Spring::with_duration_bounce`].
+    pub fn with_duration_bounce(duration: f32, bounce: f32) -> Self {
+        let mut angular_velocity_factor: f32 = -TAU;
+        let mut damping_ratio: f32 = f32::INFINITY;
+
+        if bounce > -1.0 {
+            damping_ratio = 1.0;
+
+            if bounce < 0.0 {
+                damping_ratio = 1.0 / (bounce + 1.0);
+            } else if bounce != 0.0 {
+                damping_ratio = 0.0;
+                if bounce <= 1.0 {
+                    damping_ratio = 1.0 - bounce;
+                }
+            }
+
+            if damping_ratio <= 1.0 {
+                angular_velocity_factor = TAU;
+            }
+        }
+
+        let angular_frequency =
+            (1.0 - damping_ratio * damping_ratio).abs().sqrt() * angular_velocity_factor / duration;
+        let decay_constant = damping_ratio * TAU / duration;
+
+        Self {
+            angular_frequency,
+            decay_constant,
+            mass: 1.0,
+        }
+    }
+
+    /// Creates a spring with the specified mass, stiffness, and damping.
+    ///
+    /// See [`Spring::with_mass_stiffness_damping`].
+    pub fn with_mass_stiffness_damping(
+        mass: f32,
+        stiffness: f32,
+        damping: f32,
+        allow_over_damping: bool,
+    ) -> Self {
+        let natural_frequency = (stiffness / mass).sqrt();
+        let damping_ratio = damping / (2.0 * mass);
+
+        let (angular_frequency, decay_constant) =
+            if damping_ratio > natural_frequency && !allow_over_damping {
+                (0.0, natural_frequency)
+            } else {
+                let oscillation = (stiffness / mass - damping_ratio * damping_ratio)
+                    .abs()
+                    .sqrt();
+                let angular_freq = if damping_ratio > natural_frequency {
+                    -oscillation
+                } else {
+                    oscillation
+                };
+                (angular_freq, damping_ratio)
+            };
+
+        Self {
+            angular_frequency,
+            decay_constant,
+            mass,
+        }
+    }
+
+    /// Creates a spring with the specified response and damping ratio.
+    ///
+    /// See [`Spring::with_response_damping_ratio`].
+    pub fn with_response_damping_ratio(response: f32, damping_ratio: f32) -> Self {
+        let tau_factor = if damping_ratio > 1.0 { -TAU } else { TAU };
+        let frequency_component = (1.0 - damping_ratio * damping_ratio).abs().sqrt();
+
+        Self {
+            angular_frequency: (tau_factor * frequency_component) / response,
+            decay_constant: (TAU * damping_ratio) / response,
+            mass: 1.0,
+        }
+    }
+
+    /// A smooth spring with a predefined duration and no bounce.
+    #[inline]
+    pub fn smooth() -> Self {
+        Self::with_duration_bounce(0.5, 0.0)
+    }
+
+    /// A spring with a predefined duration and small amount of bounce that
+    /// feels more snappy.
+    #[inline]
+    pub fn snappy() -> Self {
+        Self::with_duration_bounce(0.5, 0.15)
+    }
+
+    /// A spring with a predefined duration and higher amount of bounce.
+    #[inline]
+    pub fn bouncy() -> Self {
+        Self::with_duration_bounce(0.5, 0.3)
+    }
+
+    /// A spring with a short duration and a small amount of bounce, suited to
+    /// tracking direct manipulation such as drags.
+    #[inline]
+    pub fn interactive() -> Self {
+        Self::with_duration_bounce(0.15, 0.14)
+    }
+
+    /// The spring of SwiftUI's static `interpolatingSpring`, with a duration
+    /// of 0.5 seconds and no bounce.
+    #[inline]
+    pub fn interpolating() -> Self {
+        Self::with_duration_bounce(0.5, 0.0)
+    }
+}
+
+impl Spring32 {
+    /// The perceptual duration, which defines the pace of the spring.
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        let omega = self.angular_frequency;
+        let decay = self.decay_constant;
+        TAU / (decay * decay + omega * omega.abs()).sqrt()
+    }
+
+    /// How bouncy the spring is.
+    ///
+    /// See [`Spring::bounce`].
+    pub fn bounce(&self) -> f32 {
+        let half_decay = self.decay_constant / 2.0;
+        let decay_squared = self.decay_constant * self.decay_constant;
+        let frequency_squared = self.angular_frequency * self.angular_frequency;
+
+        if self.angular_frequency >= 0.0 {
+            let oscillation_period = -TAU / (frequency_squared + decay_squared).sqrt();
+            (oscillation_period * half_decay) / PI + 1.0
+        } else {
+            let decay_period = TAU / (decay_squared - frequency_squared).sqrt();
+            1.0 / ((decay_period * half_decay) / PI) - 1.0
+        }
+    }
+
+    /// The spring stiffness coefficient.
+    #[inline]
+    pub fn stiffness(&self) -> f32 {
+        // The angular frequency is negative for overdamped springs, whose
+        // natural frequency squared is the difference of the squares.
+        self.mass
+            * (self.angular_frequency * self.angular_frequency.abs()
+                + self.decay_constant * self.decay_constant)
+    }
+
+    /// Defines how the spring's motion should be damped due to the forces of
+    /// friction.
+    #[inline]
+    pub fn damping(&self) -> f32 {
+        self.decay_constant * 2.0 * self.mass
+    }
+
+    /// The stiffness of the spring, defined as an approximate duration in seconds.
+    #[inline]
+    pub fn response(&self) -> f32 {
+        let damping_squared = self.decay_constant * self.decay_constant;
+        let response_term = self.angular_frequency * self.angular_frequency.abs();
+        TAU / (damping_squared + response_term).sqrt()
+    }
+
+    /// The amount of drag applied, as a fraction of the amount needed to
+    /// produce critical damping.
+    #[inline]
+    pub fn damping_ratio(&self) -> f32 {
+        self.decay_constant * self.response() / TAU
+    }
+}
+
+impl Spring32 {
+    /// The estimated duration required for the spring system to be considered
+    /// at rest.
+    ///
+    /// This uses a `target` of 1.0, an `initial_velocity` of 0, and an `epsilon`
+    /// of 0.001.
+    pub fn settling_duration(&self) -> f32 {
+        self.settling_duration_with_velocity(1.0, 0.0, 0.001)
+    }
+
+    /// The estimated duration required for the spring system to be considered at rest.
+    ///
+    /// The epsilon value specifies the threshold for how small all subsequent
+    /// values need to be before the spring is considered to have settled.
+    ///
+    /// See [`Spring::settling_duration_with_velocity`].
+    pub fn settling_duration_with_velocity(
+        &self,
+        target: f32,
+        initial_velocity: f32,
+        epsilon: f32,
+    ) -> f32 {
+        if self.decay_constant == 0.0 {
+            return f32::INFINITY;
+        }
+
+        if self.angular_frequency < 0.0 {
+            // As in `Spring`, the remaining distance is bounded by the sum of
+            // the magnitudes of the slow and fast modes.
+            let slow_rate = -self.decay_constant - self.angular_frequency;
+            let fast_rate = self.angular_frequency - self.decay_constant;
+            let slow = -(initial_velocity + target * fast_rate) / (slow_rate - fast_rate);
+            let fast = target - slow;
+            let (slow, fast) = (slow.abs(), fast.abs());
+
+            let upper_bound = ((slow + fast) / epsilon).ln() / -slow_rate;
+            return settling_time(
+                |time| slow * (slow_rate * time).exp() + fast * (fast_rate * time).exp(),
+                0.0,
+                upper_bound,
+                epsilon,
+            );
+        }
+
+        if self.angular_frequency == 0.0 {
+            let decay = self.decay_constant;
+            let constant = target.abs();
+            let linear = (target * decay - initial_velocity).abs();
+            let peak_time = if linear > 0.0 {
+                (1.0 / decay - constant / linear).max(0.0)
+            } else {
+                0.0
+            };
+
+            let scale = constant + 2.0 * linear / (decay * std::f32::consts::E);
+            let upper_bound = (2.0 * (scale / epsilon).ln() / decay).max(peak_time);
+            return settling_time(
+                |time| (-decay * time).exp() * (constant + linear * time),
+                peak_time,
+                upper_bound,
+                epsilon,
+            );
+        }
+
+        let magnitude = (target * self.decay_constant - initial_velocity).abs() + target.abs();
+        let settling_time = -(epsilon / magnitude).ln() / self.decay_constant;
+        settling_time.max(0.0)
+    }
+
+    /// Calculates the value of the spring at a given time given a target amount of change.
+    pub fn value(&self, target: f32, initial_velocity: f32, time: f32) -> f32 {
+        if self.angular_frequency > 0.0 {
+            let (sin_val, cos_val) = (self.angular_frequency * time).sin_cos();
+            let displacement = (target * self.decay_constant - initial_velocity)
+                * (sin_val / self.angular_frequency)
+                + target * cos_val;
+            target - displacement * (-self.decay_constant * time).exp()
+        } else if self.angular_frequency < 0.0 {
+            let negative_freq_minus_damping = -self.angular_frequency - self.decay_constant;
+            let exp_term1 = (negative_freq_minus_damping * time).exp();
+            let exp_term2 = ((self.angular_frequency - self.decay_constant) * time).exp();
+
+            let damping_factor = (self.decay_constant - self.angular_frequency) * exp_term1
+                + negative_freq_minus_damping * exp_term2;
+            let scale_factor = damping_factor / (self.angular_frequency * 2.0) + 1.0;
+            let velocity_factor = (exp_term1 - exp_term2) / (self.angular_frequency * 2.0);
+
+            target * scale_factor - initial_velocity * velocity_factor
+        } else {
+            let displacement = target + (target * self.decay_constant - initial_velocity) * time;
+            target - displacement * (-self.decay_constant * time).exp()
+        }
+    }
+
+    /// Calculates the velocity of the spring at a given time given a target amount of change.
+    pub fn velocity(&self, target: f32, initial_velocity: f32, time: f32) -> f32 {
+        if self.angular_frequency > 0.0 {
+            let damping_term = (-self.decay_constant * time).exp();
+            let (sin_val, cos_val) = (self.angular_frequency * time).sin_cos();
+
+            let target_term = target
+                * ((self.angular_frequency * sin_val + self.decay_constant * cos_val)
+                    * damping_term);
+            let displacement_factor =
+                (self.decay_constant * sin_val - self.angular_frequency * cos_val) * damping_term
+                    / self.angular_frequency;
+            (target * self.decay_constant - initial_velocity) * displacement_factor + target_term
+        } else if self.angular_frequency < 0.0 {
+            let negative_freq_minus_damping = -self.angular_frequency - self.decay_constant;
+            let damping_minus_freq = self.angular_frequency - self.decay_constant;
+
+            let term1 = negative_freq_minus_damping * (negative_freq_minus_damping * time).exp();
+            let term2 = damping_minus_freq * (damping_minus_freq * time).exp();
+
+            let scale_factor = ((self.decay_constant - self.angular_frequency) * term1
+                + negative_freq_minus_damping * term2)
+                / (self.angular_frequency * 2.0);
+            let velocity_factor = (term1 - term2) / (self.angular_frequency * 2.0);
+
+            target * scale_factor - initial_velocity * velocity_factor
+        } else {
+            let damping_term = (-self.decay_constant * time).exp();
+            let time_factor = (self.decay_constant * time - 1.0) * damping_term;
+            let velocity_delta = target * self.decay_constant - initial_velocity;
+            velocity_delta * time_factor + target * (self.decay_constant * damping_term)
+        }
+    }
+
+    /// Calculates the acceleration of the spring at a given time given a target amount of change.
+    ///
+    /// See [`Spring::acceleration`].
+    pub fn acceleration(&self, target: f32, initial_velocity: f32, time: f32) -> f32 {
+        let natural_frequency_squared = self.decay_constant * self.decay_constant
+            + self.angular_frequency * self.angular_frequency.abs();
+        let value = self.value(target, initial_velocity, time);
+        let velocity = self.velocity(target, initial_velocity, time);
+        (target - value) * natural_frequency_squared - velocity * (2.0 * self.decay_constant)
+    }
+
+    /// Updates the current value and velocity of a spring.
+    ///
+    /// See [`Spring::update`].
+    pub fn update(&self, value: &mut f32, velocity: &mut f32, target: f32, delta_time: f32) {
+        let delta = target - *value;
+        let delta_velocity = self.velocity(delta, *velocity, delta_time);
+        let delta_value = self.value(delta, *velocity, delta_time);
+        *velocity = delta_velocity;
+        *value += delta_value;
+    }
+
+    /// Updates the current value and velocity of a spring, snapping them to
+    /// the target and zero once the spring has settled, and returns whether
+    /// it has.
+    ///
+    /// See [`Spring::update_with_epsilon`].
+    pub fn update_with_epsilon(
+        &self,
+        value: &mut f32,
+        velocity: &mut f32,
+        target: f32,
+        delta_time: f32,
+        epsilon: f32,
+    ) -> bool {
+        self.update(value, velocity, target, delta_time);
+        let settled = self.is_settled(*value, *velocity, target, epsilon);
+        if settled {
+            *value = target;
+            *velocity = 0.0;
+        }
+        settled
+    }
+
+    /// Returns whether a spring at `value` moving at `velocity` has come to
+    /// rest at `target`.
+    ///
+    /// See [`Spring::is_settled`].
+    pub fn is_settled(&self, value: f32, velocity: f32, target: f32, epsilon: f32) -> bool {
+        let natural_frequency = (self.decay_constant * self.decay_constant
+            + self.angular_frequency * self.angular_frequency.abs())
+        .sqrt();
+        (target - value).abs() <= epsilon && velocity.abs() <= epsilon * natural_frequency
+    }
+
+    /// Calculates the force upon the spring given a current position, target, and velocity amount of change.
+    ///
+    /// This value is in units per second squared.
+    pub fn force(&self, target: f32, position: f32, velocity: f32) -> f32 {
+        let damping_force = velocity * ((-self.decay_constant * 2.0) * self.mass);
+        let spring_force = (target - position) * (self.stiffness());
+        spring_force + damping_force
+    }
+}
+
+/// The number of bisection steps used to solve for a settling duration, which
+/// narrows any bracket to the precision of an `f32`.
+const SETTLING_BISECTION_ITERATIONS: u32 = 48;
+
+/// Solves for the time at which `envelope`, which decreases monotonically
+/// over `[low, high]`, falls to `epsilon`, like its double-precision
+/// counterpart in `Spring`.
+fn settling_time(envelope: impl Fn(f32) -> f32, low: f32, high: f32, epsilon: f32) -> f32 {
+    if envelope(low) <= epsilon {
+        return 0.0;
+    }
+    if !high.is_finite() {
+        return high;
+    }
+    let (mut low, mut high) = (low, high);
+    for _ in 0..SETTLING_BISECTION_ITERATIONS {
+        let middle = 0.5 * (low + high);
+        if envelope(middle) > epsilon {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    high
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pairs of the same spring in both precisions, covering every damping
+    /// regime.
+    fn springs() -> [(Spring32, Spring); 5] {
+        [
+            (0.5, 0.3),
+            (0.5, 0.0),
+            (0.5, -0.5),
+            (0.15, 0.14),
+            (1.0, 1.0),
+        ]
+        .map(|(duration, bounce)| {
+            (
+                Spring32::with_duration_bounce(duration as f32, bounce as f32),
+                Spring::with_duration_bounce(duration, bounce),
+            )
+        })
+    }
+
+    #[test]
+    fn evaluation_matches_double_precision() {
+        for (spring32, spring) in springs() {
+            for step in 0..100 {
+                let time = step as f64 * 0.01;
+                let value = spring32.value(1.0, 2.0, time as f32);
+                let velocity = spring32.velocity(1.0, 2.0, time as f32);
+                assert!((f64::from(value) - spring.value(1.0, 2.0, time)).abs() < 1e-4);
+                assert!((f64::from(velocity) - spring.velocity(1.0, 2.0, time)).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn settling_duration_matches_double_precision() {
+        for (spring32, spring) in springs() {
+            for (target, velocity) in [(1.0, 0.0), (1.0, -8.0), (-2.0, 5.0)] {
+                let single = spring32.settling_duration_with_velocity(target, velocity, 0.001);
+                let double = spring.settling_duration_with_velocity(
+                    f64::from(target),
+                    f64::from(velocity),
+                    0.001,
+                );
+                if double.is_finite() {
+                    assert!(
+                        (f64::from(single) - double).abs() <= 1e-4 * double.max(1.0),
+                        "{spring32:?} settles at {single} in f32 and {double} in f64",
+                    );
+                } else {
+                    assert!(single.is_infinite());
+                }
+            }
+        }
+    }
+}
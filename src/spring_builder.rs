@@ -0,0 +1,274 @@
+use crate::error::SpringError;
+use crate::spring::Spring;
+
+/// The duration and response of [`Spring::SMOOTH`].
+const DEFAULT_DURATION: f64 = 0.5;
+
+/// A parameter that can be set on a [`SpringBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpringParameter {
+    Duration,
+    Bounce,
+    Response,
+    DampingRatio,
+    Mass,
+    Stiffness,
+    Damping,
+    InitialVelocity,
+}
+
+/// A builder for [`Spring`] values.
+///
+/// Parameters that are not set fall back to those of [`Spring::SMOOTH`]. The
+/// builder remembers which parameters were set explicitly, and infers the
+/// parameterization from them:
+///
+/// * `stiffness` or `damping` selects
+///   [`with_mass_stiffness_damping`](Spring::with_mass_stiffness_damping);
+/// * `response` or `damping_ratio` selects
+///   [`with_response_damping_ratio`](Spring::with_response_damping_ratio);
+/// * otherwise, [`with_duration_bounce`](Spring::with_duration_bounce) is used.
+///
+/// A `mass` set alongside the other parameterizations is applied to the built
+/// spring without changing its motion.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SpringBuilder {
+    duration: Option<f64>,
+    bounce: Option<f64>,
+    response: Option<f64>,
+    damping_ratio: Option<f64>,
+    mass: Option<f64>,
+    stiffness: Option<f64>,
+    damping: Option<f64>,
+    allow_over_damping: bool,
+    initial_velocity: Option<f64>,
+}
+
+impl Spring {
+    /// Returns a builder for configuring a spring parameter by parameter.
+    #[inline]
+    pub fn builder() -> SpringBuilder {
+        SpringBuilder::new()
+    }
+}
+
+impl SpringBuilder {
+    /// Creates a builder with no parameters set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            duration: None,
+            bounce: None,
+            response: None,
+            damping_ratio: None,
+            mass: None,
+            stiffness: None,
+            damping: None,
+            allow_over_damping: false,
+            initial_velocity: None,
+        }
+    }
+
+    /// Sets the perceptual duration, which defines the pace of the spring.
+    #[inline]
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets how bouncy the spring should be.
+    #[inline]
+    pub fn bounce(mut self, bounce: f64) -> Self {
+        self.bounce = Some(bounce);
+        self
+    }
+
+    /// Sets the stiffness of the spring, defined as an approximate duration in
+    /// seconds.
+    #[inline]
+    pub fn response(mut self, response: f64) -> Self {
+        self.response = Some(response);
+        self
+    }
+
+    /// Sets the amount of drag applied, as a fraction of the amount needed to
+    /// produce critical damping.
+    #[inline]
+    pub fn damping_ratio(mut self, damping_ratio: f64) -> Self {
+        self.damping_ratio = Some(damping_ratio);
+        self
+    }
+
+    /// Sets the mass of the object attached to the end of the spring.
+    #[inline]
+    pub fn mass(mut self, mass: f64) -> Self {
+        self.mass = Some(mass);
+        self
+    }
+
+    /// Sets the spring stiffness coefficient.
+    #[inline]
+    pub fn stiffness(mut self, stiffness: f64) -> Self {
+        self.stiffness = Some(stiffness);
+        self
+    }
+
+    /// Sets the damping coefficient.
+    #[inline]
+    pub fn damping(mut self, damping: f64) -> Self {
+        self.damping = Some(damping);
+        self
+    }
+
+    /// Sets whether a mass, stiffness, and damping combination may produce an
+    /// overdamped spring. Defaults to `false`.
+    #[inline]
+    pub fn allow_over_damping(mut self, allow_over_damping: bool) -> Self {
+        self.allow_over_damping = allow_over_damping;
+        self
+    }
+
+    /// Sets the initial velocity the spring is expected to start with.
+    ///
+    /// The velocity is not part of the built [`Spring`], but is kept on the
+    /// builder for callers that start an animation from it.
+    #[inline]
+    pub fn initial_velocity(mut self, initial_velocity: f64) -> Self {
+        self.initial_velocity = Some(initial_velocity);
+        self
+    }
+
+    /// Returns the explicitly set value of `parameter`, or `None` if it was
+    /// never set.
+    pub fn get(&self, parameter: SpringParameter) -> Option<f64> {
+        match parameter {
+            SpringParameter::Duration => self.duration,
+            SpringParameter::Bounce => self.bounce,
+            SpringParameter::Response => self.response,
+            SpringParameter::DampingRatio => self.damping_ratio,
+            SpringParameter::Mass => self.mass,
+            SpringParameter::Stiffness => self.stiffness,
+            SpringParameter::Damping => self.damping,
+            SpringParameter::InitialVelocity => self.initial_velocity,
+        }
+    }
+
+    /// Returns whether `parameter` was set explicitly.
+    #[inline]
+    pub fn is_set(&self, parameter: SpringParameter) -> bool {
+        self.get(parameter).is_some()
+    }
+
+    /// The initial velocity, or zero if it was never set.
+    #[inline]
+    pub fn initial_velocity_value(&self) -> f64 {
+        self.initial_velocity.unwrap_or(0.0)
+    }
+
+    /// Builds the spring.
+    ///
+    /// When parameters from several parameterizations are set, mass,
+    /// stiffness, and damping take precedence over response and damping
+    /// ratio, which take precedence over duration and bounce. Use
+    /// [`try_build`](Self::try_build) to reject such combinations instead.
+    pub fn build(&self) -> Spring {
+        let mut spring = if self.is_physical() {
+            let (mass, stiffness, damping) = self.physical_parameters();
+            Spring::with_mass_stiffness_damping(mass, stiffness, damping, self.allow_over_damping)
+        } else if self.is_response() {
+            let (response, damping_ratio) = self.response_parameters();
+            Spring::with_response_damping_ratio(response, damping_ratio)
+        } else {
+            let (duration, bounce) = self.perceptual_parameters();
+            Spring::with_duration_bounce(duration, bounce)
+        };
+        if let Some(mass) = self.mass {
+            spring.mass = mass;
+        }
+        spring
+    }
+
+    /// Builds the spring, validating the parameters.
+    ///
+    /// Returns [`SpringError::ConflictingParameters`] if parameters from more
+    /// than one parameterization are set, and otherwise the error of the
+    /// corresponding `try_*` constructor.
+    pub fn try_build(&self) -> Result<Spring, SpringError> {
+        let perceptual = self.duration.is_some() || self.bounce.is_some();
+        let parameterizations = [self.is_physical(), self.is_response(), perceptual];
+        if parameterizations.iter().filter(|&&set| set).count() > 1 {
+            return Err(SpringError::ConflictingParameters);
+        }
+        if let Some(initial_velocity) = self.initial_velocity {
+            SpringError::ensure_finite(&[initial_velocity])?;
+        }
+
+        if self.is_physical() {
+            let (mass, stiffness, damping) = self.physical_parameters();
+            return Spring::try_with_mass_stiffness_damping(
+                mass,
+                stiffness,
+                damping,
+                self.allow_over_damping,
+            );
+        }
+
+        if let Some(mass) = self.mass {
+            SpringError::ensure_finite(&[mass])?;
+            if mass <= 0.0 {
+                return Err(SpringError::NonPositiveMass);
+            }
+        }
+        let mut spring = if self.is_response() {
+            let (response, damping_ratio) = self.response_parameters();
+            Spring::try_with_response_damping_ratio(response, damping_ratio)?
+        } else {
+            let (duration, bounce) = self.perceptual_parameters();
+            Spring::try_with_duration_bounce(duration, bounce)?
+        };
+        if let Some(mass) = self.mass {
+            spring.mass = mass;
+        }
+        Ok(spring)
+    }
+
+    /// The mass, stiffness, and damping, defaulting to a critically damped
+    /// spring with the pace of [`Spring::SMOOTH`].
+    fn physical_parameters(&self) -> (f64, f64, f64) {
+        let mass = self.mass.unwrap_or(1.0);
+        let stiffness = self
+            .stiffness
+            .unwrap_or_else(|| Spring::SMOOTH.stiffness() * mass);
+        let damping = self
+            .damping
+            .unwrap_or_else(|| 2.0 * (stiffness * mass).sqrt());
+        (mass, stiffness, damping)
+    }
+
+    #[inline]
+    fn response_parameters(&self) -> (f64, f64) {
+        (
+            self.response.unwrap_or(DEFAULT_DURATION),
+            self.damping_ratio.unwrap_or(1.0),
+        )
+    }
+
+    #[inline]
+    fn perceptual_parameters(&self) -> (f64, f64) {
+        (
+            self.duration.unwrap_or(DEFAULT_DURATION),
+            self.bounce.unwrap_or(0.0),
+        )
+    }
+
+    #[inline]
+    fn is_physical(&self) -> bool {
+        self.stiffness.is_some() || self.damping.is_some()
+    }
+
+    #[inline]
+    fn is_response(&self) -> bool {
+        self.response.is_some() || self.damping_ratio.is_some()
+    }
+}
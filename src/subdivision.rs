@@ -0,0 +1,67 @@
+/// The number of points inside each interval at which the approximation error
+/// is measured.
+const ERROR_SAMPLES: u32 = 16;
+/// The number of times an interval may be halved.
+const MAX_DEPTH: u32 = 24;
+/// The largest number of intervals produced, which bounds the output for
+/// tolerances too small to be met, and for previews drawn over huge widths.
+pub(crate) const MAX_INTERVALS: usize = 1 << 16;
+/// The smallest tolerance accepted, which replaces tolerances that are not
+/// finite and positive.
+pub(crate) const MIN_TOLERANCE: f64 = 1e-9;
+
+/// Returns `tolerance`, or [`MIN_TOLERANCE`] if it is smaller or is not
+/// finite.
+#[inline]
+pub(crate) fn tolerance(tolerance: f64) -> f64 {
+    if tolerance.is_finite() && tolerance > MIN_TOLERANCE {
+        tolerance
+    } else {
+        MIN_TOLERANCE
+    }
+}
+
+/// Splits the time range `0..duration` into intervals, halving each one until
+/// the approximation between its knots is within `tolerance`, and returns the
+/// knots in order.
+///
+/// `knot` creates the knot at a time, and `error` returns the error of the
+/// approximation between two knots at a fraction of the way between them.
+/// Intervals are no longer halved once they are [`MAX_DEPTH`] halvings deep or
+/// [`MAX_INTERVALS`] intervals have been produced, so the later intervals of a
+/// curve whose tolerance cannot be met may exceed it.
+pub(crate) fn subdivide<K>(
+    duration: f64,
+    tolerance: f64,
+    knot: impl Fn(f64) -> K,
+    error: impl Fn(&K, &K, f64) -> f64,
+) -> Vec<K>
+where
+    K: Clone,
+{
+    let mut start_time = 0.0;
+    let mut knots = vec![knot(start_time)];
+    // The end times of the intervals still to be checked, latest first, so
+    // that knots are produced in order.
+    let mut pending = vec![(duration, 0)];
+    while let Some((end_time, depth)) = pending.pop() {
+        let start = &knots[knots.len() - 1];
+        let end = knot(end_time);
+        // Halving replaces this interval with two, and every interval still
+        // pending produces at least one.
+        let can_halve = depth < MAX_DEPTH && knots.len() + pending.len() < MAX_INTERVALS;
+        let fits = !can_halve
+            || (1..ERROR_SAMPLES).all(|sample| {
+                let amount = f64::from(sample) / f64::from(ERROR_SAMPLES);
+                error(start, &end, amount) <= tolerance
+            });
+        if fits {
+            knots.push(end);
+            start_time = end_time;
+        } else {
+            pending.push((end_time, depth + 1));
+            pending.push(((start_time + end_time) / 2.0, depth + 1));
+        }
+    }
+    knots
+}
@@ -0,0 +1,18 @@
+/// The largest number of substeps a single update is split into, which bounds
+/// the work done for a very long frame.
+const MAX_SUBSTEPS: u32 = 256;
+
+/// Splits `delta_time` into equal substeps no longer than `max_substep`, and
+/// returns their number and duration.
+///
+/// Frames long enough to need more than [`MAX_SUBSTEPS`] substeps use that
+/// many longer substeps instead.
+pub(crate) fn substeps(delta_time: f64, max_substep: f64) -> (u32, f64) {
+    if !(delta_time > max_substep && max_substep > 0.0) {
+        return (1, delta_time);
+    }
+    let count = (delta_time / max_substep)
+        .ceil()
+        .min(f64::from(MAX_SUBSTEPS)) as u32;
+    (count, delta_time / f64::from(count))
+}
@@ -0,0 +1,97 @@
+use crate::spring::Spring;
+
+/// A precomputed table of a spring's unit step response, which evaluates the
+/// motion by linear interpolation without any trigonometry or exponentials.
+///
+/// The table covers the spring starting at rest and moving towards a target of
+/// 1, sampled evenly over its [settling duration](Spring::settling_duration),
+/// after which the value is exactly 1. The motion of an undamped spring
+/// repeats, so its table covers a single period and wraps around instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpringTable {
+    values: Vec<f64>,
+    velocities: Vec<f64>,
+    duration: f64,
+    periodic: bool,
+}
+
+impl SpringTable {
+    /// Samples the step response of `spring` at `resolution` evenly spaced
+    /// intervals.
+    ///
+    /// The interpolation error falls with the square of the resolution.
+    pub fn bake(spring: Spring, resolution: usize) -> Self {
+        let resolution = resolution.max(1);
+        let settling_duration = spring.settling_duration();
+        let periodic = !settling_duration.is_finite() && spring.angular_frequency > 0.0;
+        let duration = if settling_duration.is_finite() {
+            settling_duration
+        } else if periodic {
+            spring.period()
+        } else {
+            spring.duration()
+        };
+
+        let (mut values, mut velocities): (Vec<f64>, Vec<f64>) = (0..=resolution)
+            .map(|index| {
+                let time = duration * (index as f64 / resolution as f64);
+                (
+                    spring.value(1.0, 0.0, time),
+                    spring.velocity(1.0, 0.0, time),
+                )
+            })
+            .unzip();
+        if settling_duration.is_finite() {
+            values[resolution] = 1.0;
+            velocities[resolution] = 0.0;
+        }
+        Self {
+            values,
+            velocities,
+            duration,
+            periodic,
+        }
+    }
+
+    /// The time covered by the table.
+    #[inline]
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// The number of intervals between the samples of the table.
+    #[inline]
+    pub fn resolution(&self) -> usize {
+        self.values.len() - 1
+    }
+
+    /// Returns the step response at a given time.
+    ///
+    /// The value is 0 before time 0, and for springs that are not periodic,
+    /// the last sample of the table after its duration.
+    #[inline]
+    pub fn value_at(&self, time: f64) -> f64 {
+        self.interpolate(&self.values, time)
+    }
+
+    /// Returns the velocity of the step response at a given time.
+    #[inline]
+    pub fn velocity_at(&self, time: f64) -> f64 {
+        self.interpolate(&self.velocities, time)
+    }
+
+    fn interpolate(&self, samples: &[f64], time: f64) -> f64 {
+        let time = if self.periodic && time > self.duration {
+            time % self.duration
+        } else {
+            time
+        };
+        let position = (time / self.duration * self.resolution() as f64).max(0.0);
+        let index = position as usize;
+        if index >= self.resolution() {
+            return samples[self.resolution()];
+        }
+        let fraction = position - index as f64;
+        samples[index] + (samples[index + 1] - samples[index]) * fraction
+    }
+}
@@ -0,0 +1,69 @@
+use crate::spring::Spring;
+use crate::vector_arithmetic::VectorArithmetic;
+
+impl Spring {
+    /// The distance by which the spring trails a target moving at
+    /// `target_velocity` once the motion has settled.
+    ///
+    /// A spring following a moving target settles at a constant velocity
+    /// matching the target, which requires the pull of the spring to balance
+    /// its damping.
+    pub fn tracking_lag<V>(&self, target_velocity: V) -> V
+    where
+        V: VectorArithmetic,
+    {
+        let natural_frequency = self.natural_frequency();
+        target_velocity
+            .scaled_by(2.0 * self.decay_constant / (natural_frequency * natural_frequency))
+    }
+
+    /// Calculates the value of the spring at a given time while its target
+    /// moves at a constant velocity.
+    ///
+    /// The motion is the exact solution for a target that starts at
+    /// `target_position` relative to the value and moves at `target_velocity`,
+    /// so there is no need to retarget the spring every frame. The value
+    /// eventually moves with the target, trailing it by the
+    /// [`tracking_lag`](Self::tracking_lag).
+    ///
+    /// # Arguments
+    ///
+    /// * `target_position` - The amount of change to the target at time 0.
+    /// * `target_velocity` - The velocity of the target.
+    /// * `initial_velocity` - The initial velocity of the spring.
+    /// * `time` - The time since the start of the motion.
+    pub fn value_tracking<V>(
+        &self,
+        target_position: V,
+        target_velocity: V,
+        initial_velocity: V,
+        time: f64,
+    ) -> V
+    where
+        V: VectorArithmetic,
+    {
+        // Relative to the target trailed by the lag, the motion is that of a
+        // spring towards a fixed target.
+        let relative_target = target_position - self.tracking_lag(target_velocity.clone());
+        let relative_velocity = initial_velocity - target_velocity.clone();
+        target_velocity.scaled_by(time) + self.value(relative_target, relative_velocity, time)
+    }
+
+    /// Calculates the velocity of the spring at a given time while its target
+    /// moves at a constant velocity, as in
+    /// [`value_tracking`](Self::value_tracking).
+    pub fn velocity_tracking<V>(
+        &self,
+        target_position: V,
+        target_velocity: V,
+        initial_velocity: V,
+        time: f64,
+    ) -> V
+    where
+        V: VectorArithmetic,
+    {
+        let relative_target = target_position - self.tracking_lag(target_velocity.clone());
+        let relative_velocity = initial_velocity - target_velocity.clone();
+        target_velocity + self.velocity(relative_target, relative_velocity, time)
+    }
+}
@@ -0,0 +1,396 @@
+use std::ops::Mul;
+
+use crate::rotation::{Quaternion, Vector3};
+use crate::vector_arithmetic::componentwise_vector_arithmetic_impl;
+
+/// A two-dimensional affine transformation matrix.
+///
+/// A point `(x, y)` is transformed to `(a * x + c * y + tx, b * x + d * y + ty)`,
+/// matching the layout of Core Graphics' `CGAffineTransform`.
+///
+/// Animating the matrix elements directly produces visible shearing when
+/// rotation and scale change together. Convert the transform into a
+/// [`DecomposedTransform2D`], which can be driven by a spring, and recompose it
+/// for each frame instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub tx: f64,
+    pub ty: f64,
+}
+
+impl Default for Transform2D {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform2D {
+    /// The transform that leaves points unchanged.
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    #[inline]
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) -> Self {
+        Self { a, b, c, d, tx, ty }
+    }
+
+    /// Creates a transform that translates by the given amounts.
+    #[inline]
+    pub const fn translation(tx: f64, ty: f64) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Creates a transform that rotates counterclockwise by `angle` radians.
+    #[inline]
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates a transform that scales by the given factors.
+    #[inline]
+    pub const fn scale(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Applies the transform to the point `(x, y)`.
+    #[inline]
+    pub fn transform_point(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.tx,
+            self.b * x + self.d * y + self.ty,
+        )
+    }
+
+    /// Splits the transform into translation, rotation, scale and skew.
+    ///
+    /// The linear part is factored as `rotation * skew * scale`, where the skew
+    /// shears the x-axis. A reflection is represented by a negative vertical
+    /// scale.
+    pub fn decompose(&self) -> DecomposedTransform2D {
+        let scale_x = (self.a * self.a + self.b * self.b).sqrt();
+        let rotation = if scale_x == 0.0 {
+            0.0
+        } else {
+            self.b.atan2(self.a)
+        };
+        let (sin, cos) = rotation.sin_cos();
+        let sheared = cos * self.c + sin * self.d;
+        let scale_y = cos * self.d - sin * self.c;
+        let skew = if scale_y == 0.0 {
+            0.0
+        } else {
+            sheared / scale_y
+        };
+
+        DecomposedTransform2D {
+            translation_x: self.tx,
+            translation_y: self.ty,
+            rotation,
+            scale_x,
+            scale_y,
+            skew,
+        }
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Self;
+
+    /// Concatenates two transforms, applying `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.a * rhs.a + self.c * rhs.b,
+            self.b * rhs.a + self.d * rhs.b,
+            self.a * rhs.c + self.c * rhs.d,
+            self.b * rhs.c + self.d * rhs.d,
+            self.a * rhs.tx + self.c * rhs.ty + self.tx,
+            self.b * rhs.tx + self.d * rhs.ty + self.ty,
+        )
+    }
+}
+
+impl From<DecomposedTransform2D> for Transform2D {
+    #[inline]
+    fn from(decomposed: DecomposedTransform2D) -> Self {
+        decomposed.recompose()
+    }
+}
+
+/// A two-dimensional affine transform split into its individual components.
+///
+/// Each component is animated independently, so rotation sweeps through
+/// intermediate angles and scale changes uniformly instead of the matrix
+/// elements shearing on their way to the target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecomposedTransform2D {
+    pub translation_x: f64,
+    pub translation_y: f64,
+    /// The counterclockwise rotation, in radians.
+    pub rotation: f64,
+    pub scale_x: f64,
+    pub scale_y: f64,
+    /// The horizontal shear factor applied before rotation.
+    pub skew: f64,
+}
+
+impl Default for DecomposedTransform2D {
+    #[inline]
+    fn default() -> Self {
+        Transform2D::IDENTITY.decompose()
+    }
+}
+
+impl DecomposedTransform2D {
+    /// Combines the components back into a transformation matrix.
+    pub fn recompose(&self) -> Transform2D {
+        let (sin, cos) = self.rotation.sin_cos();
+        Transform2D::new(
+            self.scale_x * cos,
+            self.scale_x * sin,
+            self.scale_y * (self.skew * cos - sin),
+            self.scale_y * (self.skew * sin + cos),
+            self.translation_x,
+            self.translation_y,
+        )
+    }
+}
+
+impl From<Transform2D> for DecomposedTransform2D {
+    #[inline]
+    fn from(transform: Transform2D) -> Self {
+        transform.decompose()
+    }
+}
+
+componentwise_vector_arithmetic_impl! {
+    DecomposedTransform2D {
+        translation_x: f64,
+        translation_y: f64,
+        rotation: f64,
+        scale_x: f64,
+        scale_y: f64,
+        skew: f64,
+    }
+}
+
+/// A three-dimensional affine transformation.
+///
+/// A point `p` is transformed to
+/// `columns[0] * p.x + columns[1] * p.y + columns[2] * p.z + translation`.
+///
+/// As with [`Transform2D`], convert the transform into a
+/// [`DecomposedTransform3D`] to animate it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform3D {
+    /// The columns of the linear part of the transform.
+    pub columns: [Vector3; 3],
+    pub translation: Vector3,
+}
+
+impl Default for Transform3D {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform3D {
+    /// The transform that leaves points unchanged.
+    pub const IDENTITY: Self = Self::new(
+        [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ],
+        Vector3::ZERO,
+    );
+
+    #[inline]
+    pub const fn new(columns: [Vector3; 3], translation: Vector3) -> Self {
+        Self {
+            columns,
+            translation,
+        }
+    }
+
+    /// Applies the transform to the given point.
+    #[inline]
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.columns[0] * point.x
+            + self.columns[1] * point.y
+            + self.columns[2] * point.z
+            + self.translation
+    }
+
+    /// Splits the transform into translation, rotation, scale and skew.
+    ///
+    /// The linear part is orthogonalized with Gram–Schmidt, in the same way as
+    /// the CSS transform decomposition: the result is factored as
+    /// `rotation * skew * scale`. A reflection is represented by negative
+    /// scale factors.
+    pub fn decompose(&self) -> DecomposedTransform3D {
+        let [mut x_axis, mut y_axis, mut z_axis] = self.columns;
+
+        let mut scale = Vector3::ZERO;
+        scale.x = x_axis.length();
+        x_axis = normalized(x_axis, scale.x);
+
+        let mut skew_xy = x_axis.dot(&y_axis);
+        y_axis -= x_axis * skew_xy;
+        scale.y = y_axis.length();
+        y_axis = normalized(y_axis, scale.y);
+
+        let mut skew_xz = x_axis.dot(&z_axis);
+        z_axis -= x_axis * skew_xz;
+        let mut skew_yz = y_axis.dot(&z_axis);
+        z_axis -= y_axis * skew_yz;
+        scale.z = z_axis.length();
+        z_axis = normalized(z_axis, scale.z);
+
+        skew_xy = ratio(skew_xy, scale.y);
+        skew_xz = ratio(skew_xz, scale.z);
+        skew_yz = ratio(skew_yz, scale.z);
+
+        // A left-handed basis cannot be expressed as a rotation, so fold the
+        // reflection into the scale.
+        if x_axis.dot(&cross(y_axis, z_axis)) < 0.0 {
+            scale = -scale;
+            x_axis = -x_axis;
+            y_axis = -y_axis;
+            z_axis = -z_axis;
+        }
+
+        DecomposedTransform3D {
+            translation: self.translation,
+            rotation: quaternion_from_basis(x_axis, y_axis, z_axis).to_rotation_vector(),
+            scale,
+            skew: Vector3::new(skew_xy, skew_xz, skew_yz),
+        }
+    }
+}
+
+impl From<DecomposedTransform3D> for Transform3D {
+    #[inline]
+    fn from(decomposed: DecomposedTransform3D) -> Self {
+        decomposed.recompose()
+    }
+}
+
+/// A three-dimensional affine transform split into its individual components.
+///
+/// The rotation is stored as a rotation vector, so springing it moves along a
+/// rotation axis instead of interpolating matrix or quaternion elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecomposedTransform3D {
+    pub translation: Vector3,
+    /// The rotation axis scaled by the rotation angle in radians.
+    pub rotation: Vector3,
+    pub scale: Vector3,
+    /// The `xy`, `xz` and `yz` shear factors, stored in `x`, `y` and `z`.
+    pub skew: Vector3,
+}
+
+impl Default for DecomposedTransform3D {
+    #[inline]
+    fn default() -> Self {
+        Transform3D::IDENTITY.decompose()
+    }
+}
+
+impl DecomposedTransform3D {
+    /// Combines the components back into a transformation.
+    pub fn recompose(&self) -> Transform3D {
+        let rotation = Quaternion::from_rotation_vector(self.rotation);
+        let x_axis = rotation.rotate(Vector3::new(1.0, 0.0, 0.0));
+        let y_axis = rotation.rotate(Vector3::new(0.0, 1.0, 0.0));
+        let z_axis = rotation.rotate(Vector3::new(0.0, 0.0, 1.0));
+        let scale = self.scale;
+        let skew = self.skew;
+
+        Transform3D::new(
+            [
+                x_axis * scale.x,
+                (x_axis * skew.x + y_axis) * scale.y,
+                (x_axis * skew.y + y_axis * skew.z + z_axis) * scale.z,
+            ],
+            self.translation,
+        )
+    }
+}
+
+impl From<Transform3D> for DecomposedTransform3D {
+    #[inline]
+    fn from(transform: Transform3D) -> Self {
+        transform.decompose()
+    }
+}
+
+componentwise_vector_arithmetic_impl! {
+    DecomposedTransform3D {
+        translation: Vector3,
+        rotation: Vector3,
+        scale: Vector3,
+        skew: Vector3,
+    }
+}
+
+#[inline]
+fn normalized(vector: Vector3, length: f64) -> Vector3 {
+    if length == 0.0 {
+        vector
+    } else {
+        vector * (1.0 / length)
+    }
+}
+
+#[inline]
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+#[inline]
+fn cross(lhs: Vector3, rhs: Vector3) -> Vector3 {
+    Vector3::new(
+        lhs.y * rhs.z - lhs.z * rhs.y,
+        lhs.z * rhs.x - lhs.x * rhs.z,
+        lhs.x * rhs.y - lhs.y * rhs.x,
+    )
+}
+
+/// Converts an orthonormal, right-handed basis into a quaternion.
+fn quaternion_from_basis(x_axis: Vector3, y_axis: Vector3, z_axis: Vector3) -> Quaternion {
+    // `m_rc` is the element in row `r` and column `c` of the rotation matrix.
+    let (m00, m10, m20) = (x_axis.x, x_axis.y, x_axis.z);
+    let (m01, m11, m21) = (y_axis.x, y_axis.y, y_axis.z);
+    let (m02, m12, m22) = (z_axis.x, z_axis.y, z_axis.z);
+
+    // Pick the largest diagonal term to keep the square root well conditioned.
+    let trace = m00 + m11 + m22;
+    let quaternion = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        Quaternion::new((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, s / 4.0)
+    } else if m00 > m11 && m00 > m22 {
+        let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+        Quaternion::new(s / 4.0, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+    } else if m11 > m22 {
+        let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+        Quaternion::new((m01 + m10) / s, s / 4.0, (m12 + m21) / s, (m02 - m20) / s)
+    } else {
+        let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+        Quaternion::new((m02 + m20) / s, (m12 + m21) / s, s / 4.0, (m10 - m01) / s)
+    };
+    quaternion.normalized()
+}
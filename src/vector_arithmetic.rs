@@ -14,6 +14,40 @@ pub trait VectorArithmetic: AdditiveArithmetic + Clone {
         self.scale_by(scalar);
         self
     }
+
+    /// Returns this value linearly interpolated towards `other` by `t`, where
+    /// 0 gives this value and 1 gives `other`.
+    ///
+    /// This result is equivalent to `self + (other - self) * t`.
+    #[inline]
+    fn interpolated(self, other: Self, t: f64) -> Self {
+        self.clone() + (other - self).scaled_by(t)
+    }
+
+    /// Returns the dot-product of this vector arithmetic instance with another
+    /// instance.
+    ///
+    /// The default implementation derives the dot-product from
+    /// [`magnitude_squared`](Self::magnitude_squared) through the polarization
+    /// identity. Types that can compute it directly should override it.
+    fn dot(&self, other: &Self) -> f64 {
+        let sum = (self.clone() + other.clone()).magnitude_squared();
+        let difference = (self.clone() - other.clone()).magnitude_squared();
+        (sum - difference) / 4.0
+    }
+
+    /// Returns the length of this vector arithmetic instance.
+    #[inline]
+    fn magnitude(&self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Returns the distance between this vector arithmetic instance and
+    /// another instance.
+    #[inline]
+    fn distance_to(&self, other: &Self) -> f64 {
+        (other.clone() - self.clone()).magnitude()
+    }
 }
 
 macro_rules! vector_arithmetic_impl {
@@ -26,8 +60,169 @@ macro_rules! vector_arithmetic_impl {
             fn scale_by(&mut self, scalar: f64) {
                 *self = (*self as f64 * scalar) as Self;
             }
+
+            fn dot(&self, other: &Self) -> f64 {
+                (*self as f64) * (*other as f64)
+            }
         }
     )*)
 }
 
 vector_arithmetic_impl! { f32 f64 }
+
+/// Implements [`AdditiveArithmetic`] and [`VectorArithmetic`] for a type that
+/// already supports `Add`, `AddAssign`, `Sub` and `Mul<f64>`.
+///
+/// Scaling is forwarded to the type's `Mul<f64>` implementation, so only the
+/// zero value and the squared magnitude need to be provided.
+///
+/// ```
+/// # use std::ops::{Add, AddAssign, Mul, Sub};
+/// # #[derive(Debug, Clone, Copy, PartialEq)]
+/// # struct Vec2 {
+/// #     x: f64,
+/// #     y: f64,
+/// # }
+/// # impl Add for Vec2 {
+/// #     type Output = Self;
+/// #     fn add(self, rhs: Self) -> Self {
+/// #         Vec2 { x: self.x + rhs.x, y: self.y + rhs.y }
+/// #     }
+/// # }
+/// # impl AddAssign for Vec2 {
+/// #     fn add_assign(&mut self, rhs: Self) {
+/// #         *self = *self + rhs;
+/// #     }
+/// # }
+/// # impl Sub for Vec2 {
+/// #     type Output = Self;
+/// #     fn sub(self, rhs: Self) -> Self {
+/// #         Vec2 { x: self.x - rhs.x, y: self.y - rhs.y }
+/// #     }
+/// # }
+/// # impl Mul<f64> for Vec2 {
+/// #     type Output = Self;
+/// #     fn mul(self, rhs: f64) -> Self {
+/// #         Vec2 { x: self.x * rhs, y: self.y * rhs }
+/// #     }
+/// # }
+/// respring::impl_vector_arithmetic_via_mul! {
+///     Vec2, zero: Vec2 { x: 0.0, y: 0.0 }, magnitude_squared: |v| v.x * v.x + v.y * v.y
+/// }
+///
+/// # use respring::VectorArithmetic;
+/// let v = Vec2 { x: 3.0, y: 4.0 };
+/// assert_eq!(v.magnitude(), 5.0);
+/// assert_eq!(v.scaled_by(2.0), Vec2 { x: 6.0, y: 8.0 });
+/// ```
+///
+/// [`AdditiveArithmetic`]: crate::AdditiveArithmetic
+#[macro_export]
+macro_rules! impl_vector_arithmetic_via_mul {
+    ($($t:ty, zero: $zero:expr, magnitude_squared: |$v:ident| $magnitude_squared:expr);+ $(;)?) => ($(
+        impl $crate::AdditiveArithmetic for $t {
+            const ZERO: Self = $zero;
+        }
+
+        impl $crate::VectorArithmetic for $t {
+            #[inline]
+            fn magnitude_squared(&self) -> f64 {
+                let $v = self;
+                $magnitude_squared
+            }
+
+            #[inline]
+            fn scale_by(&mut self, scalar: f64) {
+                *self = ::core::clone::Clone::clone(self) * scalar;
+            }
+
+            #[inline]
+            fn scaled_by(self, scalar: f64) -> Self {
+                self * scalar
+            }
+        }
+    )+)
+}
+
+/// Implements the arithmetic operators, [`AdditiveArithmetic`] and
+/// [`VectorArithmetic`] for a struct by applying them to each field.
+///
+/// [`AdditiveArithmetic`]: crate::AdditiveArithmetic
+macro_rules! componentwise_vector_arithmetic_impl {
+    ($($t:ident { $($field:ident: $field_ty:ty),+ $(,)? })*) => ($(
+        impl ::std::ops::Add for $t {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl ::std::ops::AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl ::std::ops::Sub for $t {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl ::std::ops::SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field = self.$field - rhs.$field;)+
+            }
+        }
+
+        impl ::std::ops::Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl ::std::ops::Mul<f64> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: f64) -> Self {
+                $crate::VectorArithmetic::scaled_by(self, rhs)
+            }
+        }
+
+        impl $crate::AdditiveArithmetic for $t {
+            const ZERO: Self = Self {
+                $($field: <$field_ty as $crate::AdditiveArithmetic>::ZERO),+
+            };
+        }
+
+        impl $crate::VectorArithmetic for $t {
+            #[inline]
+            fn magnitude_squared(&self) -> f64 {
+                0.0 $(+ $crate::VectorArithmetic::magnitude_squared(&self.$field))+
+            }
+
+            #[inline]
+            fn scale_by(&mut self, scalar: f64) {
+                $($crate::VectorArithmetic::scale_by(&mut self.$field, scalar);)+
+            }
+
+            #[inline]
+            fn dot(&self, other: &Self) -> f64 {
+                0.0 $(+ $crate::VectorArithmetic::dot(&self.$field, &other.$field))+
+            }
+        }
+    )*)
+}
+
+pub(crate) use componentwise_vector_arithmetic_impl;